@@ -0,0 +1,54 @@
+//! Counts the allocations of a single calculation, because criterion only
+//! measures the time.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use pw_entropy::{PasswordInfo, PasswordInfoBuilder};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn count_allocations<F: FnOnce()>(f: F) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+#[allow(clippy::print_stdout)]
+fn main() {
+    const PASSWORD: &str = "Tr0ub4dor&3";
+
+    // Warm up the lazily collected common sequences
+    let _ = black_box(PasswordInfo::for_password(PASSWORD));
+
+    // Collects the common sequences for every password
+    let builder = PasswordInfoBuilder::new().with_extra_sequences(&[]);
+    let collected = count_allocations(|| {
+        let _ = black_box(builder.for_password(black_box(PASSWORD)));
+    });
+    let precomputed = count_allocations(|| {
+        let _ = black_box(PasswordInfo::for_password(black_box(PASSWORD)));
+    });
+
+    println!("allocations with collected sequences:   {collected}");
+    println!("allocations with precomputed sequences: {precomputed}");
+}
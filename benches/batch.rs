@@ -0,0 +1,113 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pw_entropy::{PasswordInfo, PasswordInfoBuilder};
+
+const PASSWORDS: &[&str] = &[
+    "password",
+    "123456",
+    "letmein",
+    "Tr0ub4dor&3",
+    "correct horse battery staple",
+    "qwertyuiop123",
+    "iloveyou!",
+    "ThisIsASecret",
+    "abc123abc123",
+    "P@ssw0rd2021",
+];
+
+fn batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch");
+
+    // Collects the common sequences for every password
+    let builder = PasswordInfoBuilder::new().with_extra_sequences(&[]);
+    let _ = group.bench_function("builder", |b| {
+        b.iter(|| {
+            for password in PASSWORDS {
+                let _ = black_box(builder.for_password(black_box(password)));
+            }
+        });
+    });
+
+    let _ = group.bench_function("for_password", |b| {
+        b.iter(|| {
+            for password in PASSWORDS {
+                let _ = black_box(PasswordInfo::for_password(black_box(password)));
+            }
+        });
+    });
+
+    let _ = group.bench_function("for_passwords", |b| {
+        b.iter(|| {
+            for info in PasswordInfo::for_passwords(black_box(PASSWORDS).iter().copied()) {
+                let _ = black_box(info);
+            }
+        });
+    });
+
+    group.finish();
+}
+
+/// Run with and without the feature `aho-corasick` to compare the naive
+/// matching with the automaton.
+fn sequences(c: &mut Criterion) {
+    // Pseudo-random lowercase words, so the naive matching can not stop early
+    let mut state: u32 = 42;
+    let words: Vec<String> = (0..5_000)
+        .map(|_| {
+            (0..8)
+                .map(|_| {
+                    state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                    char::from(b'a' + ((state >> 16) % 26) as u8)
+                })
+                .collect()
+        })
+        .collect();
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+    let builder = PasswordInfoBuilder::new().with_extra_sequences(&words);
+
+    // The sequences are prepared once per batch
+    let passwords: Vec<String> = (0..1_000)
+        .map(|i| format!("{}{i}", PASSWORDS[i % PASSWORDS.len()]))
+        .collect();
+    let passwords: Vec<&str> = passwords.iter().map(String::as_str).collect();
+
+    let mut group = c.benchmark_group("sequences");
+    let _ = group.sample_size(10);
+
+    let _ = group.bench_function("large_list", |b| {
+        b.iter(|| {
+            for info in builder.for_passwords(black_box(&passwords).iter().copied()) {
+                let _ = black_box(info);
+            }
+        });
+    });
+
+    group.finish();
+}
+
+#[cfg(feature = "rayon")]
+fn parallel(c: &mut Criterion) {
+    let passwords: Vec<String> = (0..10_000)
+        .map(|i| format!("{}{i}", PASSWORDS[i % PASSWORDS.len()]))
+        .collect();
+    let passwords: Vec<&str> = passwords.iter().map(String::as_str).collect();
+
+    let mut group = c.benchmark_group("parallel");
+
+    let _ = group.bench_function("for_passwords", |b| {
+        b.iter(|| {
+            PasswordInfo::for_passwords(black_box(&passwords).iter().copied()).collect::<Vec<_>>()
+        });
+    });
+
+    let _ = group.bench_function("par_for_passwords", |b| {
+        b.iter(|| PasswordInfo::par_for_passwords(black_box(&passwords)));
+    });
+
+    group.finish();
+}
+
+#[cfg(not(feature = "rayon"))]
+criterion_group!(benches, batch, sequences);
+#[cfg(feature = "rayon")]
+criterion_group!(benches, batch, sequences, parallel);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+//! The baseline of a single calculation for representative kinds of
+//! passwords.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pw_entropy::PasswordInfo;
+
+fn for_password(c: &mut Criterion) {
+    let inputs = [
+        ("short", String::from("Tr0ub4dor&3")),
+        (
+            "passphrase",
+            String::from("correct horse battery staple, purple monkey dishwasher and a lazy dog"),
+        ),
+        ("same_char", "a".repeat(1_000)),
+        ("sequences", "qwerty1234password".repeat(50)),
+    ];
+
+    let mut group = c.benchmark_group("for_password");
+    for (name, password) in &inputs {
+        let _ = group.bench_function(*name, |b| {
+            b.iter(|| PasswordInfo::for_password(black_box(password)));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, for_password);
+criterion_main!(benches);
@@ -0,0 +1,95 @@
+//! Helpers to audit password lists, like a dump file with one password per
+//! line.
+//!
+//! ## Example
+//! ```rust
+//! use std::io::Cursor;
+//! use pw_entropy::audit;
+//!
+//! let dump = Cursor::new("letmein\r\n\nTr0ub4dor&3\n");
+//! let scores: Vec<(String, f64)> = audit::score_reader(dump, true).collect();
+//! assert_eq!(2, scores.len());
+//! assert_eq!("letmein", scores[0].0);
+//! ```
+
+use std::io::BufRead;
+
+use crate::PasswordInfo;
+
+/// Scores each line of the given reader and yields the line together with
+/// its entropy in bits, see
+/// [`PasswordInfo::for_password`](PasswordInfo::for_password).
+///
+/// The trailing line break of each line, `\n` or `\r\n`, is not part of the
+/// password. Empty lines are skipped if `skip_empty` is true, otherwise they
+/// are scored as the empty password with `0.0` bits. Lines that are not valid
+/// UTF-8 are scored with the invalid bytes replaced by `U+FFFD`.
+///
+/// The lines are read lazily, so a large file is never read at once. The
+/// iterator ends at the end of the reader or at the first I/O error.
+pub fn score_reader<R: BufRead>(
+    mut reader: R,
+    skip_empty: bool,
+) -> impl Iterator<Item = (String, f64)> {
+    let mut buffer = Vec::new();
+
+    core::iter::from_fn(move || loop {
+        buffer.clear();
+        match reader.read_until(b'\n', &mut buffer) {
+            Ok(0) | Err(_) => return None,
+            Ok(_) => {}
+        }
+
+        if buffer.last() == Some(&b'\n') {
+            let _ = buffer.pop();
+            if buffer.last() == Some(&b'\r') {
+                let _ = buffer.pop();
+            }
+        }
+
+        if skip_empty && buffer.is_empty() {
+            continue;
+        }
+
+        let line = String::from_utf8_lossy(&buffer).into_owned();
+        let entropy = PasswordInfo::for_password(&line).get_entropy();
+        return Some((line, entropy));
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::{audit::score_reader, PasswordInfo};
+
+    #[test]
+    fn test_score_reader() {
+        let dump = "letmein\r\n\nTr0ub4dor&3\nno newline";
+
+        let lines: Vec<String> = score_reader(Cursor::new(dump), true)
+            .map(|(line, entropy)| {
+                let expected = PasswordInfo::for_password(&line).get_entropy();
+                assert!((expected - entropy).abs() < f64::EPSILON);
+                line
+            })
+            .collect();
+        assert_eq!(vec!["letmein", "Tr0ub4dor&3", "no newline"], lines);
+
+        let scores: Vec<(String, f64)> = score_reader(Cursor::new(dump), false).collect();
+        assert_eq!(4, scores.len());
+        assert_eq!("", scores[1].0);
+        assert!(scores[1].1.abs() < f64::EPSILON);
+
+        assert_eq!(0, score_reader(Cursor::new(""), false).count());
+        assert_eq!(0, score_reader(Cursor::new("\n\r\n"), true).count());
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let dump: &[u8] = b"pass\xFFword\n";
+        let scores: Vec<(String, f64)> = score_reader(dump, true).collect();
+        assert_eq!(1, scores.len());
+        assert_eq!("pass\u{FFFD}word", scores[0].0);
+    }
+}
@@ -0,0 +1,95 @@
+//! The models to combine the sizes of the character groups to the base.
+
+use crate::float;
+
+/// How the sizes of the character groups a password contains are combined to
+/// its [`base`](crate::PasswordInfo::base).
+///
+/// The default is [`Linear`](BaseModel::Linear).
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::{BaseModel, PasswordInfoBuilder};
+///
+/// let info = PasswordInfoBuilder::new()
+///     .base_model(BaseModel::SquareRoot)
+///     .for_password("ThisIsASecret");
+/// assert_eq!(26 + 5, info.base());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BaseModel {
+    /// The base is the sum of the sizes of all groups, so adding a single
+    /// uppercase letter to a lowercase password increases the base by `26`.
+    #[default]
+    Linear,
+    /// The largest group contributes its full size, but each additional group
+    /// only contributes the square root of its size, rounded to the nearest
+    /// integer. So adding a single uppercase letter to a lowercase password
+    /// increases the base by `5`.
+    ///
+    /// This is a stricter scoring, that dampens the benefit of each
+    /// additional group.
+    SquareRoot,
+}
+
+impl BaseModel {
+    /// Combines the given sizes of the character groups to the base.
+    pub(crate) fn combine<I: IntoIterator<Item = usize>>(self, sizes: I) -> usize {
+        let sizes = sizes.into_iter();
+
+        match self {
+            Self::Linear => sizes.sum(),
+            Self::SquareRoot => {
+                let mut largest = 0;
+                let mut roots = 0.0;
+                for size in sizes {
+                    roots += float::sqrt(size as f64);
+                    largest = largest.max(size);
+                }
+
+                // Only the additional groups are dampened
+                let additional = roots - float::sqrt(largest as f64);
+                largest + (additional + 0.5) as usize
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BaseModel, PasswordInfo, PasswordInfoBuilder};
+
+    #[test]
+    fn test_combine() {
+        assert_eq!(0, BaseModel::Linear.combine(None));
+        assert_eq!(0, BaseModel::SquareRoot.combine(None));
+        assert_eq!(26, BaseModel::SquareRoot.combine(Some(26)));
+        assert_eq!(26 + 26 + 10, BaseModel::Linear.combine(vec![26, 26, 10]));
+        // 26 + 5.10 + 3.16
+        assert_eq!(34, BaseModel::SquareRoot.combine(vec![10, 26, 26]));
+    }
+
+    #[test]
+    fn test_base_model() {
+        let linear = PasswordInfo::for_password("ThisIsASecret2");
+        let info = PasswordInfoBuilder::new()
+            .base_model(BaseModel::Linear)
+            .for_password("ThisIsASecret2");
+        assert_eq!(linear.base(), info.base());
+
+        let info = PasswordInfoBuilder::new()
+            .base_model(BaseModel::SquareRoot)
+            .for_password("ThisIsASecret2");
+        assert_eq!(34, info.base());
+        assert!(info.get_entropy() < linear.get_entropy());
+
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            // A single group is not dampened
+            let info = PasswordInfoBuilder::new()
+                .base_model(BaseModel::SquareRoot)
+                .for_password("letmein");
+            assert_eq!(26, info.base());
+        }
+    }
+}
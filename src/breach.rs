@@ -0,0 +1,179 @@
+//! The integration point for checking passwords against known breaches.
+
+#[cfg(feature = "std")]
+use std::{
+    collections::HashSet,
+    fmt,
+    io::{self, BufRead},
+};
+
+/// A source of known breached passwords, like a client of the
+/// [Have I Been Pwned](https://haveibeenpwned.com/Passwords) API or a local
+/// list of leaked passwords.
+///
+/// The crate does not check any breaches itself, use
+/// [`PasswordInfo::for_password_checked`](crate::PasswordInfo::for_password_checked)
+/// with an own implementation. Closures like `|password: &str| -> bool`
+/// implement this trait as well.
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::{BreachOracle, PasswordInfo};
+///
+/// struct LocalList(Vec<&'static str>);
+///
+/// impl BreachOracle for LocalList {
+///     fn is_breached(&self, password: &str) -> bool {
+///         self.0.contains(&password)
+///     }
+/// }
+///
+/// let oracle = LocalList(vec!["Tr0ub4dor&3"]);
+/// let (info, breached) = PasswordInfo::for_password_checked("Tr0ub4dor&3", &oracle);
+/// assert!(breached);
+/// ```
+pub trait BreachOracle {
+    /// True, if the password is known to be part of a breach.
+    fn is_breached(&self, password: &str) -> bool;
+}
+
+impl<F: Fn(&str) -> bool> BreachOracle for F {
+    fn is_breached(&self, password: &str) -> bool {
+        self(password)
+    }
+}
+
+/// A local set of breached passwords, e.g. loaded from a leaked password list.
+///
+/// The passwords are stored exactly, so the whole list is kept in memory.
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::{BreachSet, PasswordInfo};
+///
+/// let list = "123456\npassword\nTr0ub4dor&3\n";
+/// let set = BreachSet::from_reader(list.as_bytes()).expect("list is readable");
+///
+/// let (_, breached) = PasswordInfo::for_password_checked("Tr0ub4dor&3", &set);
+/// assert!(breached);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Default)]
+pub struct BreachSet(HashSet<String>);
+
+#[cfg(feature = "std")]
+impl BreachSet {
+    /// Loads a newline delimited list of breached passwords. Empty lines are
+    /// skipped and a trailing `\r` of Windows line endings is removed.
+    ///
+    /// # Errors
+    /// Returns the error of the reader, e.g. if a line is not valid UTF-8.
+    pub fn from_reader<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut set = HashSet::new();
+
+        for line in reader.lines() {
+            let mut line = line?;
+            if line.ends_with('\r') {
+                let _ = line.pop();
+            }
+            if !line.is_empty() {
+                let _ = set.insert(line);
+            }
+        }
+
+        Ok(Self(set))
+    }
+
+    /// True, if the password is part of the set.
+    #[must_use]
+    pub fn contains(&self, password: &str) -> bool {
+        self.0.contains(password)
+    }
+
+    /// The amount of passwords in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// True, if the set contains no passwords.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(feature = "std")]
+impl BreachOracle for BreachSet {
+    fn is_breached(&self, password: &str) -> bool {
+        self.contains(password)
+    }
+}
+
+/// Only shows the amount of passwords to not flood the output.
+#[cfg(feature = "std")]
+impl fmt::Debug for BreachSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BreachSet")
+            .field("len", &self.0.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use crate::BreachSet;
+    use crate::{BreachOracle, PasswordInfo};
+
+    /// An oracle that knows a single breached password.
+    struct Single(&'static str);
+
+    impl BreachOracle for Single {
+        fn is_breached(&self, password: &str) -> bool {
+            self.0 == password
+        }
+    }
+
+    #[test]
+    fn test_for_password_checked() {
+        let oracle = Single("Tr0ub4dor&3");
+
+        let (info, breached) = PasswordInfo::for_password_checked("Tr0ub4dor&3", &oracle);
+        assert!(breached);
+        assert_eq!(
+            PasswordInfo::for_password("Tr0ub4dor&3").length(),
+            info.length()
+        );
+
+        let (_, breached) = PasswordInfo::for_password_checked("correct horse", &oracle);
+        assert!(!breached);
+    }
+
+    #[test]
+    fn test_closure() {
+        let oracle = |password: &str| password.starts_with("pass");
+        assert!(PasswordInfo::for_password_checked("passw0rd", &oracle).1);
+        assert!(!PasswordInfo::for_password_checked("Tr0ub4dor&3", &oracle).1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_breach_set() {
+        let list = "123456\r\n\npassword\nTr0ub4dor&3\npassword\n";
+        let set = BreachSet::from_reader(list.as_bytes()).expect("list is readable");
+
+        assert_eq!(3, set.len());
+        assert!(set.contains("123456"));
+        assert!(set.contains("Tr0ub4dor&3"));
+        assert!(!set.contains(""));
+        assert!(!set.contains("correct horse"));
+        assert!(set.is_breached("password"));
+
+        let set = BreachSet::from_reader(&[0xff, b'\n'][..]);
+        assert!(set.is_err());
+
+        let set = BreachSet::from_reader(&b""[..]).expect("list is readable");
+        assert!(set.is_empty());
+    }
+}
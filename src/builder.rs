@@ -0,0 +1,1066 @@
+//! A builder to configure the calculation of a [`PasswordInfo`](crate::PasswordInfo).
+
+use alloc::borrow::Cow;
+
+use crate::{
+    BaseModel, CharsetConfig, Dictionary, KeyboardLayout, PasswordError, PasswordInfo, Penalty,
+    SequenceSet, MAX_REASONABLE_LENGTH,
+};
+
+/// A builder to configure how the [`PasswordInfo`](PasswordInfo) of a password
+/// is calculated.
+///
+/// The default builder behaves exactly like [`PasswordInfo::for_password`](PasswordInfo::for_password).
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::PasswordInfoBuilder;
+///
+/// let info = PasswordInfoBuilder::new()
+///     .with_extra_sequences(&["acme", "roadrunner"])
+///     .for_password("acmeRoadrunner2021");
+/// ```
+#[derive(Clone, Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PasswordInfoBuilder<'a> {
+    /// The common sequences that will be removed from the password.
+    pub(crate) sequences: Cow<'a, [&'a str]>,
+    /// The dictionary words that are searched anywhere in the password.
+    pub(crate) dictionary: Dictionary,
+    /// The character groups that are used to calculate the base.
+    pub(crate) charset: CharsetConfig<'a>,
+    /// A palindrome is removed from the password.
+    pub(crate) strip_palindrome: bool,
+    /// The amount of mirrored pairs of a palindrome that may differ.
+    pub(crate) palindrome_tolerance: usize,
+    /// The common sequences are removed from the password.
+    pub(crate) strip_sequences: bool,
+    /// Repeating characters and patterns are removed from the password.
+    pub(crate) strip_repeats: bool,
+    /// Common sequences are also detected if they contain leet substitutions.
+    pub(crate) detect_leet: bool,
+    /// Separator characters are ignored while detecting common sequences.
+    pub(crate) ignore_separators_in_sequences: bool,
+    /// The keyboard layout to detect keyboard walks with.
+    pub(crate) keyboard_layout: Option<KeyboardLayout>,
+    /// The case of strictly alternating case patterns is removed.
+    pub(crate) detect_case_pattern: bool,
+    /// The case of a leading capital like in `Password` is removed.
+    pub(crate) dampen_leading_capital: bool,
+    /// Dates like `1990` or `12251999` are reduced to a small length.
+    pub(crate) detect_dates: bool,
+    /// Repeating characters are compared case-insensitively.
+    pub(crate) case_insensitive_dedup: bool,
+    /// The amount of repeating characters that are kept of each run.
+    pub(crate) max_repeat: usize,
+    /// How the removed common sequences are penalized.
+    pub(crate) sequence_penalty: Penalty,
+    /// The entropy of the arrangement of the character groups is added.
+    pub(crate) include_pattern_entropy: bool,
+    /// How the sizes of the character groups are combined to the base.
+    pub(crate) base_model: BaseModel,
+    /// The base below which the strength is capped at weak.
+    pub(crate) min_meaningful_base: u16,
+    /// A password that only consists of whitespace and separators has no
+    /// strength.
+    pub(crate) weak_whitespace_only: bool,
+    /// The password after each stripping stage is retained.
+    pub(crate) trace: bool,
+    /// The stripped length below which a password is too short.
+    pub(crate) min_reasonable_length: usize,
+    /// The stripped length above which a password is suspiciously long.
+    pub(crate) max_reasonable_length: usize,
+    /// The minimum amount of characters of a password.
+    pub(crate) min_length: usize,
+    /// The password is normalized to NFC before the calculation.
+    #[cfg(feature = "unicode-normalization")]
+    pub(crate) normalize: bool,
+    /// Each script of the letters beyond ASCII contributes its own base.
+    #[cfg(feature = "unicode-script")]
+    pub(crate) script_aware_base: bool,
+}
+
+impl Default for PasswordInfoBuilder<'_> {
+    fn default() -> Self {
+        Self {
+            sequences: crate::common_sequences(),
+            dictionary: Dictionary::default(),
+            charset: CharsetConfig::default(),
+            strip_palindrome: true,
+            palindrome_tolerance: 0,
+            strip_sequences: true,
+            strip_repeats: true,
+            detect_leet: false,
+            ignore_separators_in_sequences: false,
+            keyboard_layout: None,
+            detect_case_pattern: false,
+            dampen_leading_capital: false,
+            detect_dates: false,
+            case_insensitive_dedup: false,
+            max_repeat: 1,
+            sequence_penalty: Penalty::Remove,
+            include_pattern_entropy: false,
+            base_model: BaseModel::Linear,
+            min_meaningful_base: 0,
+            weak_whitespace_only: false,
+            trace: false,
+            min_reasonable_length: 0,
+            max_reasonable_length: MAX_REASONABLE_LENGTH,
+            min_length: 0,
+            #[cfg(feature = "unicode-normalization")]
+            normalize: false,
+            #[cfg(feature = "unicode-script")]
+            script_aware_base: false,
+        }
+    }
+}
+
+impl<'a> PasswordInfoBuilder<'a> {
+    /// Creates a new builder that uses the built-in list of common sequences.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given sequences to the list of common sequences that will be
+    /// removed from the password. Empty sequences are ignored.
+    #[must_use]
+    pub fn with_extra_sequences(mut self, sequences: &[&'a str]) -> Self {
+        self.sequences.to_mut().extend_from_slice(sequences);
+        self
+    }
+
+    /// Replaces the list of common sequences that will be removed from the
+    /// password with the given sequences. Empty sequences are ignored.
+    #[must_use]
+    pub fn replace_sequences(mut self, sequences: &[&'a str]) -> Self {
+        self.sequences = Cow::Owned(sequences.to_vec());
+        self
+    }
+
+    /// Adds the given words to the dictionary that is searched anywhere in the
+    /// password, e.g. a list of common English words. The words are compared
+    /// case-insensitively and words shorter than 4 characters are ignored.
+    ///
+    /// The longest dictionary word in the password is removed and contributes
+    /// `log_2(dictionary_len)` bits instead, because an attacker only needs to
+    /// guess which word was chosen. This is repeated until no word is left.
+    /// The removed words are part of the
+    /// [`matched sequences`](PasswordInfo::matched_sequences).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfoBuilder;
+    ///
+    /// let info = PasswordInfoBuilder::new()
+    ///     .with_dictionary(&["dragon", "monkey", "sunshine"])
+    ///     .for_password("7Dragon!");
+    /// assert_eq!(2, info.length());
+    /// assert_eq!(&["dragon"], info.matched_sequences());
+    /// ```
+    #[must_use]
+    pub fn with_dictionary(mut self, words: &[&str]) -> Self {
+        self.dictionary.extend(words);
+        self
+    }
+
+    /// Uses the given character groups to calculate the base of the password.
+    #[must_use]
+    pub const fn charset(mut self, charset: CharsetConfig<'a>) -> Self {
+        self.charset = charset;
+        self
+    }
+
+    /// Removes a palindrome like `abccba` from the password, so only one half
+    /// of it counts. Enabled by default.
+    #[must_use]
+    pub const fn strip_palindrome(mut self, strip_palindrome: bool) -> Self {
+        self.strip_palindrome = strip_palindrome;
+        self
+    }
+
+    /// Sets the amount of mirrored character pairs that may differ, so a
+    /// near-palindrome like `abccbx` is removed like `abccba`. The default is
+    /// `0`, which only removes exact palindromes.
+    ///
+    /// At least one pair must still match and only the first half of the
+    /// password is kept, so `abccbx` becomes `abc`.
+    #[must_use]
+    pub const fn palindrome_tolerance(mut self, palindrome_tolerance: usize) -> Self {
+        self.palindrome_tolerance = palindrome_tolerance;
+        self
+    }
+
+    /// Removes the common sequences like `qwerty` from the password. Enabled
+    /// by default.
+    #[must_use]
+    pub const fn strip_sequences(mut self, strip_sequences: bool) -> Self {
+        self.strip_sequences = strip_sequences;
+        self
+    }
+
+    /// Removes repeating characters like `aaa` and repeating patterns like
+    /// `abcabc` from the password. Enabled by default.
+    ///
+    /// Disable all three stripping passes to estimate the raw keyspace of the
+    /// password.
+    #[must_use]
+    pub const fn strip_repeats(mut self, strip_repeats: bool) -> Self {
+        self.strip_repeats = strip_repeats;
+        self
+    }
+
+    /// Detects common sequences even if they contain common leet substitutions
+    /// like `p@ssw0rd` (`@` for `a`, `0` for `o`, `1` for `l` or `i`, `3` for
+    /// `e`, `$` for `s`, ...). Disabled by default.
+    ///
+    /// The substitutions are only used to detect the sequences. The character
+    /// groups are still calculated from the original characters.
+    #[must_use]
+    pub const fn detect_leet(mut self, detect_leet: bool) -> Self {
+        self.detect_leet = detect_leet;
+        self
+    }
+
+    /// Detects common sequences even if they are interspersed with separator
+    /// characters like `p-a-s-s-w-o-r-d` or `p a s s w o r d`. Disabled by
+    /// default.
+    ///
+    /// The separators are only ignored to detect the sequences. They are kept
+    /// in the password, so they still count towards the length and the base.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfoBuilder;
+    ///
+    /// let info = PasswordInfoBuilder::new()
+    ///     .ignore_separators_in_sequences(true)
+    ///     .for_password("p-a-s-s-w-o-r-d");
+    /// assert!(info.stripped_sequence());
+    /// ```
+    #[must_use]
+    pub const fn ignore_separators_in_sequences(mut self, ignore: bool) -> Self {
+        self.ignore_separators_in_sequences = ignore;
+        self
+    }
+
+    /// Detects keyboard walks like `qazwsx` on the given keyboard layout and
+    /// reduces each walk to its first character. Disabled by default.
+    ///
+    /// See [`KeyboardLayout`](KeyboardLayout) for the available layouts.
+    #[must_use]
+    pub fn detect_keyboard_walks(mut self, layout: KeyboardLayout) -> Self {
+        self.keyboard_layout = Some(layout);
+        self
+    }
+
+    /// Detects strictly alternating case patterns like `tHiStHiS` and removes
+    /// the case information, because it is deterministic. Disabled by
+    /// default.
+    ///
+    /// At least six letters with a case must alternate, so a random mixed
+    /// case password is rarely mistaken for a pattern. The pattern is detected
+    /// before the common sequences, so `pAsSwOrD` is removed as `password`.
+    #[must_use]
+    pub const fn detect_case_pattern(mut self, detect_case_pattern: bool) -> Self {
+        self.detect_case_pattern = detect_case_pattern;
+        self
+    }
+
+    /// Removes the case of a leading capital, if it is the only uppercase
+    /// letter of the password like in `Password1`, see
+    /// [`PasswordInfo::is_leading_capital_only`](PasswordInfo::is_leading_capital_only).
+    /// Disabled by default.
+    ///
+    /// Capitalizing the first letter is so common that an attacker tries it
+    /// first, so the leading capital counts as a lowercase letter and does not
+    /// add the uppercase group to the base.
+    #[must_use]
+    pub const fn dampen_leading_capital(mut self, dampen_leading_capital: bool) -> Self {
+        self.dampen_leading_capital = dampen_leading_capital;
+        self
+    }
+
+    /// Detects years like `1990` and dates like `010190` or `12251999` and
+    /// reduces them to a small representative length, because attackers can
+    /// enumerate them cheaply. Disabled by default.
+    ///
+    /// Only whole runs of digits are checked, so random digits that merely
+    /// contain a date are untouched. The common sequences like `0123456789`
+    /// are removed before the dates are detected.
+    #[must_use]
+    pub const fn detect_dates(mut self, detect_dates: bool) -> Self {
+        self.detect_dates = detect_dates;
+        self
+    }
+
+    /// Removes repeating characters that only differ in case, so `aAaA` is
+    /// reduced like `aaaa`. Disabled by default.
+    #[must_use]
+    pub const fn case_insensitive_dedup(mut self, case_insensitive_dedup: bool) -> Self {
+        self.case_insensitive_dedup = case_insensitive_dedup;
+        self
+    }
+
+    /// Truncates each run of repeating characters to the given amount instead
+    /// of a single character, so with `2` the intentional `aa` is kept, but
+    /// `aaaa` becomes `aa`. The default is `1`, which reduces `aa` to `a`. A
+    /// value of `0` is treated like `1`.
+    #[must_use]
+    pub const fn max_repeat(mut self, max_repeat: usize) -> Self {
+        self.max_repeat = max_repeat;
+        self
+    }
+
+    /// Sets how the removed common sequences are penalized. The default is
+    /// [`Penalty::Remove`](Penalty::Remove), with
+    /// [`Penalty::FixedBits`](Penalty::FixedBits) `passwordpassword` gets a
+    /// higher entropy than `password`.
+    #[must_use]
+    pub const fn sequence_penalty_mode(mut self, penalty: Penalty) -> Self {
+        self.sequence_penalty = penalty;
+        self
+    }
+
+    /// Adds the entropy of the arrangement of the character groups to the
+    /// entropy of the password. Disabled by default.
+    ///
+    /// This is `log_2` of the amount of distinct orders of the
+    /// [`group composition`](PasswordInfo::group_composition), see
+    /// [`GroupComposition::arrangement_entropy`](crate::GroupComposition::arrangement_entropy).
+    /// It models the uncertainty of an attacker who does not know where each
+    /// group appears, so a password that mixes several groups evenly gets
+    /// more bits than one that mostly consists of a single group.
+    #[must_use]
+    pub const fn include_pattern_entropy(mut self, include: bool) -> Self {
+        self.include_pattern_entropy = include;
+        self
+    }
+
+    /// Sets how the sizes of the character groups are combined to the base.
+    /// The default is [`BaseModel::Linear`](BaseModel::Linear), with
+    /// [`BaseModel::SquareRoot`](BaseModel::SquareRoot) each additional group
+    /// contributes less.
+    #[must_use]
+    pub const fn base_model(mut self, base_model: BaseModel) -> Self {
+        self.base_model = base_model;
+        self
+    }
+
+    /// Sets the minimum base a password must have to be rated above
+    /// [`Weak`](crate::PasswordStrength::Weak) or to be
+    /// [`acceptable`](PasswordInfo::is_acceptable) at all. Disabled by default.
+    ///
+    /// A long password of a tiny alphabet like `*&*!&*!&*!*&!` has a lot of
+    /// entropy by its length alone, but is easy to guess by its alphabet. The
+    /// raw [`entropy`](PasswordInfo::get_entropy) is not affected.
+    #[must_use]
+    pub const fn min_meaningful_base(mut self, min_meaningful_base: u16) -> Self {
+        self.min_meaningful_base = min_meaningful_base;
+        self
+    }
+
+    /// Rates a password that only consists of whitespace and separators like
+    /// `"    "` or `"- - -"` as [`VeryWeak`](crate::PasswordStrength::VeryWeak)
+    /// and never [`acceptable`](PasswordInfo::is_acceptable), see
+    /// [`is_whitespace_only`](PasswordInfo::is_whitespace_only). Disabled by
+    /// default.
+    ///
+    /// The raw [`entropy`](PasswordInfo::get_entropy) is not affected.
+    #[must_use]
+    pub const fn weak_whitespace_only(mut self, weak_whitespace_only: bool) -> Self {
+        self.weak_whitespace_only = weak_whitespace_only;
+        self
+    }
+
+    /// Retains the password after each stripping stage in an
+    /// [`AnalysisTrace`](crate::AnalysisTrace), see
+    /// [`PasswordInfo::trace`](PasswordInfo::trace). Disabled by default.
+    ///
+    /// This is meant for debugging and educational tooling only. The trace
+    /// keeps the plaintext password and its intermediate stages in memory for
+    /// as long as the info lives, which defeats the purpose of the feature
+    /// `zeroize` for every copy of the trace. Never enable it while rating
+    /// real passwords.
+    #[must_use]
+    pub const fn trace(mut self, trace: bool) -> Self {
+        self.trace = trace;
+        self
+    }
+
+    /// Sets the stripped length below which a password is
+    /// [`too short`](PasswordInfo::is_too_short). Disabled by default.
+    #[must_use]
+    pub const fn min_reasonable_length(mut self, min_reasonable_length: usize) -> Self {
+        self.min_reasonable_length = min_reasonable_length;
+        self
+    }
+
+    /// Sets the stripped length above which a password is
+    /// [`suspiciously long`](PasswordInfo::is_suspiciously_long). The default
+    /// is [`MAX_REASONABLE_LENGTH`](MAX_REASONABLE_LENGTH).
+    #[must_use]
+    pub const fn max_reasonable_length(mut self, max_reasonable_length: usize) -> Self {
+        self.max_reasonable_length = max_reasonable_length;
+        self
+    }
+
+    /// The minimum amount of characters a password must have before stripping.
+    /// Only used by [`try_for_password`](PasswordInfoBuilder::try_for_password).
+    #[must_use]
+    pub const fn min_length(mut self, min_length: usize) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
+    /// Normalizes the password to the unicode normalization form C (NFC)
+    /// before the calculation. Disabled by default.
+    ///
+    /// Without normalization, visually identical passwords can have different
+    /// chars. The composed `"caf\u{e9}"` and the decomposed `"cafe\u{301}"`
+    /// both look like `café`, but the decomposed one is one char longer and
+    /// only contains ASCII letters, so both get a different entropy.
+    ///
+    /// Requires the feature `unicode-normalization`.
+    #[cfg(feature = "unicode-normalization")]
+    #[must_use]
+    pub const fn normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Lets each Unicode script of the letters beyond ASCII contribute its own
+    /// base, summed, instead of a single
+    /// [`unicode letter base`](CharsetConfig::unicode_letter_base) for all of
+    /// them. Disabled by default.
+    ///
+    /// A password that mixes Latin and Cyrillic letters like `pароль` makes an
+    /// attacker search the keyspace of both scripts. Small alphabets like
+    /// Cyrillic or Greek count all their letters, all other scripts count as
+    /// the unicode letter base. The ASCII letters are still part of the lower
+    /// and upper groups. See
+    /// [`PasswordInfo::scripts_present`](PasswordInfo::scripts_present).
+    ///
+    /// The [`base`](crate::IncrementalScorer::base) of an
+    /// [`IncrementalScorer`](crate::IncrementalScorer) ignores this setting,
+    /// its [`info`](crate::IncrementalScorer::info) does not.
+    ///
+    /// Requires the feature `unicode-script`.
+    #[cfg(feature = "unicode-script")]
+    #[must_use]
+    pub const fn script_aware_base(mut self, script_aware_base: bool) -> Self {
+        self.script_aware_base = script_aware_base;
+        self
+    }
+
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for the given password
+    /// using the configuration of this builder.
+    #[must_use]
+    pub fn for_password(&self, password: &str) -> PasswordInfo {
+        self.analyze_password(password, &self.sequence_chars())
+    }
+
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for each of the given
+    /// passwords using the configuration of this builder.
+    ///
+    /// The common sequences are only prepared once for all passwords, which
+    /// makes this faster than calling
+    /// [`for_password`](PasswordInfoBuilder::for_password) for each password,
+    /// especially for a large list of custom sequences.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfoBuilder;
+    ///
+    /// let builder = PasswordInfoBuilder::new().with_extra_sequences(&["acme"]);
+    /// let lengths: Vec<usize> = builder
+    ///     .for_passwords(["acme2021", "Tr0ub4dor&3"].iter().copied())
+    ///     .map(|info| info.length())
+    ///     .collect();
+    /// # #[cfg(not(feature = "large-wordlist"))]
+    /// assert_eq!(vec![4, 11], lengths);
+    /// ```
+    pub fn for_passwords<'s, I>(&'s self, passwords: I) -> ForPasswords<'s, 'a, I::IntoIter>
+    where
+        I: IntoIterator<Item = &'s str>,
+    {
+        ForPasswords {
+            builder: self,
+            sequences: self.sequence_chars(),
+            passwords: passwords.into_iter(),
+        }
+    }
+
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for the given password
+    /// with the given prepared sequences.
+    pub(crate) fn analyze_password(&self, password: &str, sequences: &SequenceSet) -> PasswordInfo {
+        #[cfg(feature = "unicode-normalization")]
+        if self.normalize {
+            use unicode_normalization::UnicodeNormalization;
+
+            return PasswordInfo::analyze(password.nfc().collect(), self, sequences, false);
+        }
+
+        PasswordInfo::analyze(password.chars().collect(), self, sequences, false)
+    }
+
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for the given raw
+    /// bytes using the configuration of this builder, e.g. for secrets that
+    /// are not valid UTF-8.
+    ///
+    /// Each printable ASCII byte is classified like the ASCII character of the
+    /// same value. All other bytes, including the control bytes, are part of
+    /// the extended group of [`EXTENDED_BYTE_BASE`](crate::EXTENDED_BYTE_BASE)
+    /// bytes, regardless of the character groups.
+    #[must_use]
+    pub fn for_bytes(&self, bytes: &[u8]) -> PasswordInfo {
+        let password = bytes.iter().map(|&b| char::from(b)).collect();
+
+        PasswordInfo::analyze(password, self, &self.sequence_chars(), true)
+    }
+
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for the given password
+    /// using the configuration of this builder, but counts grapheme clusters
+    /// instead of chars.
+    ///
+    /// A grapheme cluster is what a human perceives as a single symbol, like
+    /// `e` followed by a combining accent or an emoji with modifiers like
+    /// `👨‍👩‍👧‍👦`. Each cluster is represented by its first char, so the length
+    /// can not be inflated by stuffing combining characters into the password.
+    ///
+    /// Requires the feature `unicode-segmentation`.
+    #[cfg(feature = "unicode-segmentation")]
+    #[must_use]
+    pub fn for_password_graphemes(&self, password: &str) -> PasswordInfo {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        #[cfg(feature = "unicode-normalization")]
+        if self.normalize {
+            use unicode_normalization::UnicodeNormalization;
+
+            #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+            let mut normalized: alloc::string::String = password.nfc().collect();
+            let info = self.analyze_graphemes(normalized.graphemes(true));
+
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut normalized);
+
+            return info;
+        }
+
+        self.analyze_graphemes(password.graphemes(true))
+    }
+
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for the given grapheme
+    /// clusters, each represented by its first char.
+    #[cfg(feature = "unicode-segmentation")]
+    fn analyze_graphemes<'p, I: Iterator<Item = &'p str>>(&self, graphemes: I) -> PasswordInfo {
+        let password = graphemes
+            .filter_map(|grapheme| grapheme.chars().next())
+            .collect();
+
+        PasswordInfo::analyze(password, self, &self.sequence_chars(), false)
+    }
+
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for the given password
+    /// using the configuration of this builder, if the password is valid.
+    ///
+    /// # Errors
+    /// Returns [`PasswordError::Empty`](PasswordError::Empty) if the password
+    /// is empty and [`PasswordError::TooShort`](PasswordError::TooShort) if the
+    /// password has less characters than the
+    /// [`min_length`](PasswordInfoBuilder::min_length).
+    pub fn try_for_password(&self, password: &str) -> Result<PasswordInfo, PasswordError> {
+        let length = password.chars().count();
+
+        if length == 0 {
+            Err(PasswordError::Empty)
+        } else if length < self.min_length {
+            Err(PasswordError::TooShort(self.min_length))
+        } else {
+            Ok(self.for_password(password))
+        }
+    }
+
+    /// The prepared common sequences. The built-in list is only prepared once
+    /// if the feature `std` is activated.
+    pub(crate) fn sequence_chars(&self) -> Cow<'static, SequenceSet> {
+        #[cfg(feature = "std")]
+        if let Cow::Borrowed(sequences) = self.sequences {
+            if core::ptr::eq(sequences, crate::common_sequence_list()) {
+                return Cow::Borrowed(crate::common_sequence_chars());
+            }
+        }
+
+        Cow::Owned(SequenceSet::new(&self.sequences))
+    }
+}
+
+/// An iterator over the [`PasswordInfo`](PasswordInfo) of each password,
+/// created by [`PasswordInfoBuilder::for_passwords`](PasswordInfoBuilder::for_passwords).
+#[derive(Clone, Debug)]
+pub struct ForPasswords<'s, 'a, I> {
+    /// The builder that configures the calculation.
+    builder: &'s PasswordInfoBuilder<'a>,
+    /// The common sequences of the builder, prepared once for all passwords.
+    sequences: Cow<'static, SequenceSet>,
+    /// The remaining passwords.
+    passwords: I,
+}
+
+impl<'s, I: Iterator<Item = &'s str>> Iterator for ForPasswords<'s, '_, I> {
+    type Item = PasswordInfo;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let password = self.passwords.next()?;
+
+        Some(self.builder.analyze_password(password, &self.sequences))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.passwords.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        common_sequences, PasswordInfo, PasswordInfoBuilder, PasswordStrength, Penalty, Policy,
+    };
+
+    #[test]
+    fn test_default_builder() {
+        for password in &[
+            "",
+            "letmein",
+            "LetMeIn",
+            "asdf|password|asdf|qwerty",
+            "abba",
+        ] {
+            let expected = PasswordInfo::for_password(password);
+            let actual = PasswordInfoBuilder::new().for_password(password);
+            assert_eq!(expected.length(), actual.length());
+            assert_eq!(expected.base(), actual.base());
+        }
+    }
+
+    #[test]
+    fn test_extra_sequences() {
+        let info = PasswordInfoBuilder::new()
+            .with_extra_sequences(&["acme"])
+            .for_password("acmepassword");
+        assert_eq!(0, info.length());
+
+        let info = PasswordInfoBuilder::new()
+            .with_extra_sequences(&["acme"])
+            .for_password("acme!");
+        assert_eq!(1, info.length());
+    }
+
+    #[test]
+    fn test_detect_leet() {
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            let info = PasswordInfoBuilder::new().for_password("p@ssw0rd");
+            assert_eq!("p@sw0rd".len(), info.length());
+        }
+
+        let info = PasswordInfoBuilder::new()
+            .detect_leet(true)
+            .for_password("p@ssw0rd?X");
+        assert_eq!(2, info.length());
+        assert!(info.has_other_special_character());
+        assert!(!info.has_replace_character());
+        assert!(!info.has_digit());
+    }
+
+    #[test]
+    fn test_ignore_separators_in_sequences() {
+        for password in &["p-a-s-s-w-o-r-d", "p a s s w o r d"] {
+            let info = PasswordInfoBuilder::new().for_password(password);
+            assert!(!info.stripped_sequence());
+
+            let info = PasswordInfoBuilder::new()
+                .ignore_separators_in_sequences(true)
+                .for_password(password);
+            assert!(info.stripped_sequence());
+            assert_eq!(&["password"], info.matched_sequences());
+            assert_eq!(1, info.length());
+            assert_eq!(5, info.base());
+        }
+    }
+
+    #[test]
+    fn test_include_pattern_entropy() {
+        let password = "abCD12";
+        let info = PasswordInfoBuilder::new().for_password(password);
+        let with_pattern = PasswordInfoBuilder::new()
+            .include_pattern_entropy(true)
+            .for_password(password);
+
+        let expected = info.get_entropy() + info.group_composition().arrangement_entropy();
+        assert!((expected - with_pattern.get_entropy()).abs() < 1e-9);
+        assert!(with_pattern.get_entropy() > info.get_entropy());
+    }
+
+    #[test]
+    fn test_detect_case_pattern() {
+        let info = PasswordInfoBuilder::new().for_password("tHiSiSmY");
+        assert_eq!(52, info.base());
+
+        let builder = PasswordInfoBuilder::new().detect_case_pattern(true);
+        let info = builder.for_password("tHiSiSmY");
+        assert_eq!(26, info.base());
+        assert!(!info.has_upper_character());
+
+        assert_eq!(0, builder.for_password("pAsSwOrD").length());
+        assert_eq!(52, builder.for_password("xKqTRmWpZ").base());
+    }
+
+    #[test]
+    fn test_dampen_leading_capital() {
+        let info = PasswordInfoBuilder::new().for_password("Xkcdrules7");
+        assert!(info.is_leading_capital_only());
+        assert_eq!(62, info.base());
+
+        let builder = PasswordInfoBuilder::new().dampen_leading_capital(true);
+        let info = builder.for_password("Xkcdrules7");
+        assert!(info.is_leading_capital_only());
+        assert!(!info.has_upper_character());
+        assert_eq!(36, info.base());
+
+        let info = builder.for_password("XkcdRules7");
+        assert!(!info.is_leading_capital_only());
+        assert_eq!(62, info.base());
+
+        assert!(PasswordInfo::for_chars("Xkcdrules7".chars()).is_leading_capital_only());
+        assert!(!PasswordInfo::for_chars("xkcdrules7".chars()).is_leading_capital_only());
+    }
+
+    #[test]
+    fn test_detect_dates() {
+        let info = PasswordInfoBuilder::new().for_password("Secret1987");
+        assert_eq!(10, info.length());
+
+        let info = PasswordInfoBuilder::new()
+            .detect_dates(true)
+            .for_password("Secret1987");
+        assert_eq!(8, info.length());
+
+        // `123456789` is removed as common sequence first
+        let info = PasswordInfoBuilder::new()
+            .detect_dates(true)
+            .for_password("X0123456789Y");
+        assert_eq!(2, info.length());
+    }
+
+    #[test]
+    fn test_sequence_chars() {
+        let sequences = PasswordInfoBuilder::new().sequence_chars();
+        assert_eq!(common_sequences().len(), sequences.len());
+        #[cfg(feature = "std")]
+        assert!(matches!(sequences, std::borrow::Cow::Borrowed(_)));
+
+        let sequences = PasswordInfoBuilder::new()
+            .replace_sequences(&["acme"])
+            .sequence_chars();
+        assert_eq!(&[vec!['a', 'c', 'm', 'e']], &**sequences);
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn test_for_password_graphemes() {
+        // `e` followed by a combining acute accent
+        let password = "cafe\u{301}Xy";
+        assert_eq!(7, PasswordInfo::for_password(password).length());
+        assert_eq!(6, PasswordInfo::for_password_graphemes(password).length());
+
+        let stuffed = "Z\u{351}\u{34b}\u{31c}a\u{33e}\u{345}l\u{302}\u{331}g\u{303}\u{353}o\u{308}";
+        let info = PasswordInfo::for_password_graphemes(stuffed);
+        assert_eq!(5, info.length());
+        assert_eq!(5, info.original_length());
+        assert_eq!(52, info.base());
+
+        // A family emoji of people joined with zero width joiners
+        let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}";
+        assert_eq!(7, PasswordInfo::for_password(family).length());
+        assert_eq!(1, PasswordInfo::for_password_graphemes(family).length());
+
+        assert_eq!(
+            PasswordInfo::for_password("Tr0ub4dor&3").length(),
+            PasswordInfo::for_password_graphemes("Tr0ub4dor&3").length()
+        );
+    }
+
+    #[cfg(feature = "unicode-script")]
+    #[test]
+    fn test_script_aware_base() {
+        use crate::Script;
+
+        let password = "p\u{430}\u{440}\u{43e}\u{43b}\u{44c}";
+        let info = PasswordInfoBuilder::new().for_password(password);
+        assert_eq!(
+            vec![Script::Latin, Script::Cyrillic],
+            info.scripts_present()
+        );
+        assert_eq!(26 + 62, info.base());
+
+        let builder = PasswordInfoBuilder::new().script_aware_base(true);
+        assert_eq!(26 + 66, builder.for_password(password).base());
+
+        let info = builder.for_password("\u{3b1}\u{3b2}\u{3b3}\u{43f}\u{440}\u{438}\u{e9}");
+        assert_eq!(48 + 66 + 62, info.base());
+        assert_eq!(26, builder.for_password("plain").base());
+        assert!(builder.for_password("1234!").scripts_present().is_empty());
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn test_normalize() {
+        let composed = "Caf\u{e9}Xy";
+        let decomposed = "Cafe\u{301}Xy";
+
+        let info = PasswordInfoBuilder::new().for_password(composed);
+        assert_eq!(6, info.length());
+        assert!(info.has_unicode_letter());
+        let info = PasswordInfoBuilder::new().for_password(decomposed);
+        assert_eq!(7, info.length());
+        assert!(!info.has_unicode_letter());
+
+        let builder = PasswordInfoBuilder::new().normalize(true);
+        let composed = builder.for_password(composed);
+        let decomposed = builder.for_password(decomposed);
+        assert_eq!(composed.length(), decomposed.length());
+        assert_eq!(composed.base(), decomposed.base());
+        assert!(decomposed.has_unicode_letter());
+    }
+
+    #[test]
+    fn test_strip_passes() {
+        let password = "qwertyaaXaaytrewq";
+        assert_eq!(
+            2,
+            PasswordInfoBuilder::new().for_password(password).length()
+        );
+
+        let info = PasswordInfoBuilder::new()
+            .strip_palindrome(false)
+            .for_password(password);
+        assert_eq!(3, info.length());
+        assert!(!info.stripped_palindrome());
+
+        let info = PasswordInfoBuilder::new()
+            .strip_sequences(false)
+            .for_password(password);
+        assert_eq!(8, info.length());
+        assert!(!info.stripped_sequence());
+
+        let info = PasswordInfoBuilder::new()
+            .strip_repeats(false)
+            .for_password(password);
+        assert_eq!(3, info.length());
+        assert!(!info.stripped_repeat());
+
+        let info = PasswordInfoBuilder::new()
+            .strip_palindrome(false)
+            .strip_sequences(false)
+            .strip_repeats(false)
+            .for_password(password);
+        assert_eq!(password.len(), info.length());
+    }
+
+    #[test]
+    fn test_case_insensitive_dedup() {
+        let info = PasswordInfoBuilder::new().for_password("aAAbBB");
+        assert_eq!(4, info.length());
+        assert_eq!(52, info.base());
+
+        let info = PasswordInfoBuilder::new()
+            .case_insensitive_dedup(true)
+            .for_password("aAAbBB");
+        assert_eq!(2, info.length());
+        assert_eq!(26, info.base());
+        assert!(info.stripped_repeat());
+    }
+
+    #[test]
+    fn test_reasonable_length() {
+        let builder = PasswordInfoBuilder::new()
+            .min_reasonable_length(16)
+            .max_reasonable_length(20);
+
+        let info = builder.for_password("Tr0ub4dor&3");
+        assert!(info.is_too_short());
+        assert!(!info.is_suspiciously_long());
+        assert_eq!(PasswordStrength::Weak, info.strength());
+        assert!(info.suggestions().contains(&"Use a longer password."));
+
+        let info = builder.for_password("Tr0ub4dor&3 correct horse");
+        assert!(!info.is_too_short());
+        assert!(info.is_suspiciously_long());
+        assert_eq!(PasswordStrength::Strong, info.strength());
+    }
+
+    #[test]
+    fn test_min_meaningful_base() {
+        let password = "*&*!&*!&*!*&!*&!&*&!*!&*&!*&!*!&*!*&!*";
+
+        let info = PasswordInfoBuilder::new().for_password(password);
+        assert_eq!(0, info.min_meaningful_base());
+        assert!(info.strength() > PasswordStrength::Weak);
+
+        let info = PasswordInfoBuilder::new()
+            .min_meaningful_base(26)
+            .for_password(password);
+        assert_eq!(5, info.base());
+        assert_eq!(PasswordStrength::Weak, info.strength());
+        assert!(!info.is_acceptable(&Policy::default()));
+        assert!(info.get_entropy() > 60.0);
+
+        let info = PasswordInfoBuilder::new()
+            .min_meaningful_base(26)
+            .for_password("Tr0ub4dor&3");
+        assert_eq!(PasswordStrength::Strong, info.strength());
+        assert!(info.is_acceptable(&Policy::default()));
+    }
+
+    #[test]
+    fn test_weak_whitespace_only() {
+        let password = "- -_ .--  . _-.. .-_ -- .._ -.- _._ -- .-_. ";
+
+        let info = PasswordInfoBuilder::new().for_password(password);
+        assert!(info.is_whitespace_only());
+        assert!(info.strength() > PasswordStrength::Weak);
+        assert!(info.is_acceptable(&Policy::default()));
+
+        let builder = PasswordInfoBuilder::new().weak_whitespace_only(true);
+        let info = builder.for_password(password);
+        assert_eq!(PasswordStrength::VeryWeak, info.strength());
+        assert!(!info.is_acceptable(&Policy::default()));
+        assert!(info.get_entropy() > 60.0);
+
+        for password in &["    ", "----", "\u{3000}-"] {
+            let info = builder.for_password(password);
+            assert!(info.is_whitespace_only());
+            assert_eq!(PasswordStrength::VeryWeak, info.strength());
+        }
+
+        for password in &["", "- a -", "Tr0ub4dor&3"] {
+            assert!(!builder.for_password(password).is_whitespace_only());
+        }
+        assert!(PasswordInfo::for_chars("  --".chars()).is_whitespace_only());
+        assert!(!PasswordInfo::for_chars("".chars()).is_whitespace_only());
+        assert_eq!(
+            PasswordStrength::Strong,
+            builder.for_password("Tr0ub4dor&3").strength()
+        );
+    }
+
+    #[test]
+    fn test_sequence_penalty_mode() {
+        let builder = PasswordInfoBuilder::new();
+        assert!(builder.for_password("password").get_entropy().abs() < f64::EPSILON);
+        assert!(builder.for_password("passwordpassword").get_entropy().abs() < f64::EPSILON);
+
+        let builder = PasswordInfoBuilder::new().sequence_penalty_mode(Penalty::FixedBits);
+        let bits = (common_sequences().len() as f64).log2();
+
+        let info = builder.for_password("password");
+        assert_eq!(0, info.length());
+        assert!((bits - info.get_entropy()).abs() < 1e-9);
+
+        let info = builder.for_password("passwordpassword");
+        assert!((2.0 * bits - info.get_entropy()).abs() < 1e-9);
+
+        let info = builder.for_password("qwertyXy");
+        let expected = PasswordInfo::for_password("qwertyXy").get_entropy() + bits;
+        assert!((expected - info.get_entropy()).abs() < 1e-9);
+
+        let info = builder.for_password("Tr0ub4dor&3");
+        let expected = PasswordInfo::for_password("Tr0ub4dor&3").get_entropy();
+        assert!((expected - info.get_entropy()).abs() < f64::EPSILON);
+
+        // Empty and duplicate sequences are not counted
+        let info = PasswordInfoBuilder::new()
+            .replace_sequences(&["acme", "", "road", "acme", ""])
+            .sequence_penalty_mode(Penalty::FixedBits)
+            .for_password("acme");
+        assert_eq!(0, info.length());
+        assert!((1.0 - info.get_entropy()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_with_dictionary() {
+        let builder = PasswordInfoBuilder::new().with_dictionary(&["dragon", "monkey"]);
+
+        let info = builder.for_password("xDragonmonkey7");
+        assert_eq!(2, info.length());
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            assert_eq!(&["dragon", "monkey"], info.matched_sequences());
+            let expected = PasswordInfo::for_password("x7").get_entropy() + 2.0;
+            assert!((expected - info.get_entropy()).abs() < f64::EPSILON);
+        }
+
+        let info = builder.for_password("dra9gon");
+        assert_eq!(7, info.length());
+        assert!(info.matched_sequences().is_empty());
+    }
+
+    #[test]
+    fn test_palindrome_tolerance() {
+        let info = PasswordInfo::for_password("abcdcbx");
+        assert!(!info.stripped_palindrome());
+        assert_eq!(7, info.length());
+
+        let info = PasswordInfoBuilder::new()
+            .palindrome_tolerance(1)
+            .for_password("abcdcbx");
+        assert!(info.stripped_palindrome());
+        assert_eq!(4, info.length());
+    }
+
+    #[test]
+    fn test_max_repeat() {
+        let info = PasswordInfo::for_password("xaaybbbz");
+        assert_eq!(5, info.length());
+
+        let info = PasswordInfoBuilder::new()
+            .max_repeat(2)
+            .for_password("xaaybbbz");
+        assert!(info.stripped_repeat());
+        assert_eq!(7, info.length());
+
+        let info = PasswordInfoBuilder::new()
+            .max_repeat(3)
+            .for_password("xaaybbbz");
+        assert!(!info.stripped_repeat());
+        assert_eq!(8, info.length());
+
+        // The runs kept by `max_repeat` are not shortened by the later passes
+        let builder = PasswordInfoBuilder::new().max_repeat(2);
+        for (password, length) in &[("aab", 3), ("aaaa", 2), ("aabb", 4), ("aaab", 3)] {
+            assert_eq!(
+                *length,
+                builder.for_password(password).length(),
+                "{password}"
+            );
+        }
+        let builder = PasswordInfoBuilder::new().max_repeat(3);
+        assert_eq!(3, builder.for_password("aaaa").length());
+        assert_eq!(1, PasswordInfo::for_password("aaaa").length());
+    }
+
+    #[test]
+    fn test_replace_sequences() {
+        let info = PasswordInfoBuilder::new()
+            .replace_sequences(&["acme"])
+            .for_password("acmeqwerty");
+        assert_eq!("qwerty".len(), info.length());
+
+        let info = PasswordInfoBuilder::new()
+            .replace_sequences(&[])
+            .for_password("qwerty");
+        assert_eq!("qwerty".len(), info.length());
+    }
+}
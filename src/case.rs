@@ -0,0 +1,153 @@
+//! The detection of predictable case patterns like `tHiStHiS` or `Password`.
+
+use alloc::vec::Vec;
+
+/// The minimum amount of letters with a case, that must alternate to be a
+/// case pattern. A random mixed case password of this length alternates only
+/// with a probability of `2 / 2^6 ≈ 3%`.
+const MIN_CASE_PATTERN_LENGTH: usize = 6;
+
+/// Removes the case information in place, if the case of the letters strictly
+/// alternates like `tHiStHiS` or `PaSsWoRd`.
+///
+/// Only letters with a case are considered, so `tH1s-Is` also alternates.
+/// The letters are lowercased, because their case is deterministic and adds
+/// no entropy.
+///
+/// If `positions` are given, they are adjusted like the characters, so they
+/// still belong to the same characters.
+///
+/// Returns true, if the password had a case pattern.
+pub fn remove_case_pattern(password: &mut Vec<char>, positions: Option<&mut Vec<usize>>) -> bool {
+    let mut cased = password
+        .iter()
+        .filter(|c| c.is_lowercase() || c.is_uppercase())
+        .map(|c| c.is_uppercase());
+
+    let Some(mut previous) = cased.next() else {
+        return false;
+    };
+    let mut count = 1;
+    for upper in cased {
+        if upper == previous {
+            return false;
+        }
+        previous = upper;
+        count += 1;
+    }
+
+    if count < MIN_CASE_PATTERN_LENGTH {
+        return false;
+    }
+
+    // Some characters lowercase to multiple characters, which all belong to
+    // the position of the original character.
+    if let Some(positions) = positions {
+        *positions = password
+            .iter()
+            .zip(positions.iter())
+            .flat_map(|(c, &position)| c.to_lowercase().map(move |_| position))
+            .collect();
+    }
+    *password = password.iter().flat_map(|c| c.to_lowercase()).collect();
+    true
+}
+
+/// True, if the first character of the password is its only uppercase
+/// letter and at least one lowercase letter follows, like `Password1`.
+pub fn is_leading_capital_only(password: &[char]) -> bool {
+    let Some((first, rest)) = password.split_first() else {
+        return false;
+    };
+
+    first.is_uppercase()
+        && rest.iter().any(|c| c.is_lowercase())
+        && !rest.iter().any(|c| c.is_uppercase())
+}
+
+/// Lowercases the first character of the password in place, if it is the
+/// only uppercase letter, see [`is_leading_capital_only`](is_leading_capital_only).
+///
+/// If `positions` are given, they are adjusted like the characters, see
+/// [`remove_case_pattern`](remove_case_pattern).
+///
+/// Returns true, if the password had a leading capital.
+pub fn remove_leading_capital(
+    password: &mut Vec<char>,
+    positions: Option<&mut Vec<usize>>,
+) -> bool {
+    if !is_leading_capital_only(password) {
+        return false;
+    }
+
+    let lower: Vec<char> = password[0].to_lowercase().collect();
+    if let Some(positions) = positions {
+        let first = positions[0];
+        drop(positions.splice(..1, lower.iter().map(|_| first)));
+    }
+    drop(password.splice(..1, lower));
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::case::{is_leading_capital_only, remove_case_pattern, remove_leading_capital};
+
+    #[test]
+    fn test_remove_case_pattern() {
+        let mut password: Vec<char> = "tHiStHiS".chars().collect();
+        assert!(remove_case_pattern(&mut password, None));
+        let expected: Vec<char> = "thisthis".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "PaS-sW0oRd!".chars().collect();
+        assert!(remove_case_pattern(&mut password, None));
+        let expected: Vec<char> = "pas-sw0ord!".chars().collect();
+        assert_eq!(expected, password);
+
+        // Too short to be distinguished from a random case
+        let mut password: Vec<char> = "aBcD".chars().collect();
+        assert!(!remove_case_pattern(&mut password, None));
+        let expected: Vec<char> = "aBcD".chars().collect();
+        assert_eq!(expected, password);
+
+        // A random mixed case is untouched
+        let mut password: Vec<char> = "xKqTRmWpZ".chars().collect();
+        assert!(!remove_case_pattern(&mut password, None));
+        let expected: Vec<char> = "xKqTRmWpZ".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "1234".chars().collect();
+        assert!(!remove_case_pattern(&mut password, None));
+    }
+
+    #[test]
+    fn test_remove_leading_capital() {
+        for (password, expected) in &[
+            ("Password1", true),
+            ("Émile!", true),
+            ("P", false),
+            ("P4SS", false),
+            ("PassWord", false),
+            ("password", false),
+            ("1Password", false),
+            ("", false),
+        ] {
+            let chars: Vec<char> = password.chars().collect();
+            assert_eq!(*expected, is_leading_capital_only(&chars), "{password}");
+        }
+
+        let mut password: Vec<char> = "Password1".chars().collect();
+        assert!(remove_leading_capital(&mut password, None));
+        let expected: Vec<char> = "password1".chars().collect();
+        assert_eq!(expected, password);
+        assert!(!remove_leading_capital(&mut password, None));
+
+        // `İ` lowercases to two characters, which both belong to its position
+        let mut password: Vec<char> = "İzmir".chars().collect();
+        let mut positions: Vec<usize> = (0..password.len()).collect();
+        assert!(remove_leading_capital(&mut password, Some(&mut positions)));
+        assert_eq!(6, password.len());
+        assert_eq!(vec![0, 0, 1, 2, 3, 4], positions);
+    }
+}
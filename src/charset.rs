@@ -0,0 +1,137 @@
+//! The configuration of the character groups of a password.
+
+use crate::{
+    DIGIT_CHARS, LOWER_CHARS, OTHER_SPECIAL_CHARS, REPLACE_CHARS, SEPARATOR_CHARS,
+    UNICODE_LETTER_BASE, UPPER_CHARS,
+};
+
+/// The character groups that are used to calculate the base of a password.
+///
+/// The base is the sum of the sizes of all groups the password contains at
+/// least one character of. The default configuration uses the
+/// [`REPLACE_CHARS`](REPLACE_CHARS), [`SEPARATOR_CHARS`](SEPARATOR_CHARS),
+/// [`OTHER_SPECIAL_CHARS`](OTHER_SPECIAL_CHARS), [`LOWER_CHARS`](LOWER_CHARS),
+/// [`UPPER_CHARS`](UPPER_CHARS) and [`DIGIT_CHARS`](DIGIT_CHARS) groups.
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::{CharsetConfig, PasswordInfo};
+///
+/// // A system that only allows a restricted set of special characters.
+/// let config = CharsetConfig {
+///     replace: "!@",
+///     separator: "-",
+///     other_special: "",
+///     ..CharsetConfig::default()
+/// };
+/// let info = PasswordInfo::for_password_with_charset("ThisIsASecret!", &config);
+/// assert_eq!(26 + 26 + 2, info.base());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CharsetConfig<'a> {
+    /// The list of the replace characters.
+    pub replace: &'a str,
+    /// The list of the separator characters.
+    pub separator: &'a str,
+    /// The list of the special characters that are neither a replace nor
+    /// separator character.
+    pub other_special: &'a str,
+    /// The list of lower characters.
+    pub lower: &'a str,
+    /// The list of upper characters.
+    pub upper: &'a str,
+    /// The list of digits.
+    pub digit: &'a str,
+    /// The base contribution of letters that are neither ASCII nor part of any
+    /// other group.
+    pub unicode_letter_base: u16,
+}
+
+impl Default for CharsetConfig<'_> {
+    fn default() -> Self {
+        Self {
+            replace: REPLACE_CHARS,
+            separator: SEPARATOR_CHARS,
+            other_special: OTHER_SPECIAL_CHARS,
+            lower: LOWER_CHARS,
+            upper: UPPER_CHARS,
+            digit: DIGIT_CHARS,
+            unicode_letter_base: UNICODE_LETTER_BASE,
+        }
+    }
+}
+
+impl<'a> CharsetConfig<'a> {
+    /// The characters of the six character groups in the order of
+    /// [`GROUPS`](crate::GROUPS).
+    pub(crate) const fn groups(&self) -> [&'a str; 6] {
+        [
+            self.replace,
+            self.separator,
+            self.other_special,
+            self.lower,
+            self.upper,
+            self.digit,
+        ]
+    }
+
+    /// The amount of printable ASCII characters, from the space `' '` to the
+    /// tilde `'~'`, that are not part of any of the six character groups.
+    pub(crate) fn unclassified_count(&self) -> usize {
+        (' '..='~').filter(|&c| !self.contains(c)).count()
+    }
+
+    /// True, if the given character is part of any of the six character groups.
+    pub(crate) fn contains(&self, c: char) -> bool {
+        self.groups().iter().any(|group| group.contains(c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CharsetConfig, PasswordInfo};
+
+    #[test]
+    fn test_default_charset() {
+        for password in &["", "letmein", "LetMeIn", "!_\"aA0", "Straße"] {
+            let expected = PasswordInfo::for_password(password);
+            let actual =
+                PasswordInfo::for_password_with_charset(password, &CharsetConfig::default());
+            assert_eq!(expected.base(), actual.base());
+            assert_eq!(expected.length(), actual.length());
+        }
+    }
+
+    #[test]
+    fn test_custom_charset() {
+        let config = CharsetConfig {
+            replace: "!@",
+            separator: "",
+            other_special: "",
+            ..CharsetConfig::default()
+        };
+
+        let info = PasswordInfo::for_password_with_charset("Secret!", &config);
+        assert!(info.has_replace_character());
+        assert_eq!(26 + 26 + 2, info.base());
+
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            // Printable ASCII characters that are not part of any group contribute
+            // to the base together
+            let info = PasswordInfo::for_password_with_charset("secret_", &config);
+            assert!(!info.has_separator_character());
+            assert!(info.has_unclassified());
+            assert_eq!(26 + 95 - (2 + 26 + 26 + 10), info.base());
+        }
+
+        // Unicode letters that are part of a group are not counted twice
+        let config = CharsetConfig {
+            lower: "abcdefghijklmnopqrstuvwxyzäöüß",
+            ..CharsetConfig::default()
+        };
+        let info = PasswordInfo::for_password_with_charset("straße", &config);
+        assert!(!info.has_unicode_letter());
+        assert_eq!(30, info.base());
+    }
+}
@@ -0,0 +1,123 @@
+//! The composition of a password by character groups.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::float;
+
+/// The amount of characters of the stripped password in each character group.
+///
+/// Each character is only counted once in the first group it is part of, in
+/// the order of the fields. Characters that are not part of any of the six
+/// groups, like letters beyond ASCII, are counted as `other`. Therefore the
+/// [`total`](GroupComposition::total) is always the stripped length of the
+/// password.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "zeroize", derive(Zeroize))]
+pub struct GroupComposition {
+    /// The amount of replace characters.
+    pub replace: usize,
+    /// The amount of separator characters.
+    pub separator: usize,
+    /// The amount of other special characters.
+    pub other_special: usize,
+    /// The amount of lower characters.
+    pub lower: usize,
+    /// The amount of upper characters.
+    pub upper: usize,
+    /// The amount of digits.
+    pub digit: usize,
+    /// The amount of characters that are not part of any of the six groups.
+    pub other: usize,
+}
+
+impl GroupComposition {
+    /// The total amount of characters, which is the stripped length of the
+    /// password.
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.replace
+            + self.separator
+            + self.other_special
+            + self.lower
+            + self.upper
+            + self.digit
+            + self.other
+    }
+
+    /// The entropy in bits of the arrangement of the groups, which is `log_2`
+    /// of the multinomial coefficient `total! / (replace! * separator! * ...)`.
+    ///
+    /// This is the amount of distinct orders of the groups for this
+    /// composition, e.g. `3` for one upper and two lower characters: `Ull`,
+    /// `lUl` and `llU`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfo;
+    ///
+    /// let composition = PasswordInfo::for_password("Abc").group_composition();
+    /// assert!((composition.arrangement_entropy() - 3.0_f64.log2()).abs() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn arrangement_entropy(&self) -> f64 {
+        let counts = [
+            self.replace,
+            self.separator,
+            self.other_special,
+            self.lower,
+            self.upper,
+            self.digit,
+            self.other,
+        ];
+
+        log2_factorial(self.total()) - counts.iter().map(|&n| log2_factorial(n)).sum::<f64>()
+    }
+}
+
+/// Calculates `log_2(n!)` as the sum of the logarithms to avoid an overflow.
+fn log2_factorial(n: usize) -> f64 {
+    (2..=n).map(|i| float::log2(i as f64)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GroupComposition, PasswordInfo};
+
+    #[test]
+    fn test_arrangement_entropy() {
+        assert!(GroupComposition::default().arrangement_entropy().abs() < f64::EPSILON);
+
+        let composition = PasswordInfo::for_password("abcdef").group_composition();
+        assert!(composition.arrangement_entropy().abs() < 1e-9);
+
+        // 6! / (2! * 2! * 2!) = 90
+        let composition = PasswordInfo::for_password("abCD12").group_composition();
+        assert!((composition.arrangement_entropy() - 90.0_f64.log2()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_group_composition() {
+        let composition = PasswordInfo::for_password("").group_composition();
+        assert_eq!(GroupComposition::default(), composition);
+
+        let composition = PasswordInfo::for_password("Secret_1!#é").group_composition();
+        let expected = GroupComposition {
+            replace: 1,
+            separator: 1,
+            other_special: 1,
+            lower: 5,
+            upper: 1,
+            digit: 1,
+            other: 1,
+        };
+        assert_eq!(expected, composition);
+
+        let info = PasswordInfo::for_password("ThisIsASecret!€");
+        assert_eq!(info.length(), info.group_composition().total());
+        assert_eq!(1, info.group_composition().other);
+    }
+}
@@ -0,0 +1,132 @@
+//! The detection of dates like `1990` or `12251999`.
+
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+/// The range of years that are considered plausible in a password.
+const YEARS: RangeInclusive<u32> = 1900..=2099;
+
+/// The amount of digits a detected year is reduced to. There are 200
+/// plausible years, which is about the keyspace of two digits.
+const YEAR_LENGTH: usize = 2;
+
+/// The amount of digits a detected date is reduced to. There are about 36500
+/// plausible dates, which is about the keyspace of four to five digits.
+const DATE_LENGTH: usize = 4;
+
+/// Reduces dates in the password in place to a small representative length.
+///
+/// Only whole runs of digits are checked, so a date that is part of a longer
+/// run of random digits is untouched. The following runs are detected:
+///
+/// - 4 digits: a year like `1990` is reduced to 2 digits.
+/// - 6 digits: a date like `010190` in the formats `DDMMYY`, `MMDDYY` and
+///   `YYMMDD` is reduced to 4 digits.
+/// - 8 digits: a date like `12251999` in the formats `DDMMYYYY`, `MMDDYYYY`
+///   and `YYYYMMDD` is reduced to 4 digits.
+///
+/// Years must be between 1900 and 2099.
+///
+/// If `positions` are given, the entries of each removed character are
+/// removed from them, too.
+pub fn remove_dates(password: &mut Vec<char>, mut positions: Option<&mut Vec<usize>>) {
+    let mut start = 0;
+
+    while start < password.len() {
+        if !password[start].is_ascii_digit() {
+            start += 1;
+            continue;
+        }
+
+        let end = password[start..]
+            .iter()
+            .position(|c| !c.is_ascii_digit())
+            .map_or(password.len(), |len| start + len);
+
+        if let Some(length) = reduced_length(&password[start..end]) {
+            drop(password.drain((start + length)..end));
+            if let Some(positions) = positions.as_deref_mut() {
+                drop(positions.drain((start + length)..end));
+            }
+            start += length;
+        } else {
+            start = end;
+        }
+    }
+}
+
+/// The length the given run of digits is reduced to, if it is a date.
+fn reduced_length(digits: &[char]) -> Option<usize> {
+    let number = |range: core::ops::Range<usize>| {
+        digits[range]
+            .iter()
+            .filter_map(|c| c.to_digit(10))
+            .fold(0, |number, digit| number * 10 + digit)
+    };
+
+    let is_date = match digits.len() {
+        4 => return YEARS.contains(&number(0..4)).then_some(YEAR_LENGTH),
+        // DDMMYY, MMDDYY and YYMMDD
+        6 => {
+            is_valid_date(number(0..2), number(2..4))
+                || is_valid_date(number(2..4), number(0..2))
+                || is_valid_date(number(4..6), number(2..4))
+        }
+        // DDMMYYYY, MMDDYYYY and YYYYMMDD
+        8 => {
+            (YEARS.contains(&number(4..8))
+                && (is_valid_date(number(0..2), number(2..4))
+                    || is_valid_date(number(2..4), number(0..2))))
+                || (YEARS.contains(&number(0..4)) && is_valid_date(number(6..8), number(4..6)))
+        }
+        _ => false,
+    };
+
+    is_date.then_some(DATE_LENGTH)
+}
+
+/// True, if the day exists in the month of any year.
+const fn is_valid_date(day: u32, month: u32) -> bool {
+    let days = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => 29,
+        _ => return false,
+    };
+
+    day >= 1 && day <= days
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::date::remove_dates;
+
+    #[test]
+    fn test_remove_dates() {
+        let mut password: Vec<char> = "Secret1990".chars().collect();
+        remove_dates(&mut password, None);
+        let expected: Vec<char> = "Secret19".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "010190!12251999!19991225".chars().collect();
+        remove_dates(&mut password, None);
+        let expected: Vec<char> = "0101!1225!1999".chars().collect();
+        assert_eq!(expected, password);
+
+        // Random digits that merely resemble a date are untouched
+        let mut password: Vec<char> = "3141|9999|319901|13131990|8419905".chars().collect();
+        remove_dates(&mut password, None);
+        let expected: Vec<char> = "3141|9999|319901|13131990|8419905".chars().collect();
+        assert_eq!(expected, password);
+
+        // A date within a longer run of digits is untouched
+        let mut password: Vec<char> = "73219904".chars().collect();
+        remove_dates(&mut password, None);
+        let expected: Vec<char> = "73219904".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = Vec::new();
+        remove_dates(&mut password, None);
+        assert!(password.is_empty());
+    }
+}
@@ -0,0 +1,147 @@
+//! The detection of dictionary words embedded anywhere in a password.
+
+use alloc::{string::String, vec::Vec};
+use core::ops::Range;
+
+/// The minimum length of a dictionary word. Shorter words occur by chance in
+/// too many random passwords.
+pub const MIN_WORD_LENGTH: usize = 4;
+
+/// A sorted list of dictionary words to search a password for.
+///
+/// The words are compared case-insensitively. Each position of the password is
+/// checked for each possible word length with a binary search, so the search
+/// takes `O(n * max_len * log(words))` instead of checking every word.
+#[derive(Clone, Debug, Default)]
+pub struct Dictionary {
+    /// The lowercase words, sorted and without duplicates.
+    words: Vec<Vec<char>>,
+    /// The length of the longest word.
+    max_len: usize,
+}
+
+impl Dictionary {
+    /// Adds the given words to the dictionary. Words shorter than
+    /// [`MIN_WORD_LENGTH`](MIN_WORD_LENGTH) are ignored.
+    pub fn extend(&mut self, words: &[&str]) {
+        self.words.extend(
+            words
+                .iter()
+                .map(|word| {
+                    word.chars()
+                        .flat_map(char::to_lowercase)
+                        .collect::<Vec<_>>()
+                })
+                .filter(|word| word.len() >= MIN_WORD_LENGTH),
+        );
+        self.words.sort_unstable();
+        self.words.dedup();
+        self.max_len = self.words.iter().map(Vec::len).max().unwrap_or(0);
+    }
+
+    /// The amount of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// True, if the dictionary contains no words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Repeatedly removes the longest dictionary word from the password until
+    /// no word is left and returns the removed words in the order of their
+    /// removal.
+    ///
+    /// If `positions` are given, the entries of each removed character are
+    /// removed from them, too.
+    pub fn remove_words(
+        &self,
+        password: &mut Vec<char>,
+        mut positions: Option<&mut Vec<usize>>,
+    ) -> Vec<String> {
+        let mut removed = Vec::new();
+
+        while let Some((range, index)) = self.longest_word(password) {
+            if let Some(positions) = positions.as_deref_mut() {
+                drop(positions.drain(range.clone()));
+            }
+            drop(password.drain(range));
+            removed.push(self.words[index].iter().collect());
+        }
+
+        removed
+    }
+
+    /// The position of the longest dictionary word in the password and the
+    /// index of the word. If several words have the same length, the first
+    /// one is returned.
+    fn longest_word(&self, password: &[char]) -> Option<(Range<usize>, usize)> {
+        let mut longest: Option<(Range<usize>, usize)> = None;
+
+        for start in 0..password.len() {
+            let longest_len = longest.as_ref().map_or(0, |(range, _)| range.len());
+            let max_len = self.max_len.min(password.len() - start);
+
+            for len in (MIN_WORD_LENGTH.max(longest_len + 1)..=max_len).rev() {
+                if let Some(index) = self.find(&password[start..(start + len)]) {
+                    longest = Some((start..(start + len), index));
+                    break;
+                }
+            }
+        }
+
+        longest
+    }
+
+    /// The index of the word that equals the window, ignoring case.
+    fn find(&self, window: &[char]) -> Option<usize> {
+        self.words
+            .binary_search_by(|word| {
+                word.iter()
+                    .copied()
+                    .cmp(window.iter().flat_map(|c| c.to_lowercase()))
+            })
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dictionary;
+
+    /// Creates a new dictionary of the given words.
+    fn new_dictionary(words: &[&str]) -> Dictionary {
+        let mut dictionary = Dictionary::default();
+        dictionary.extend(words);
+        dictionary
+    }
+
+    #[test]
+    fn test_new() {
+        let dictionary = new_dictionary(&["Dragon", "cat", "monkey", "dragon"]);
+        assert_eq!(2, dictionary.len());
+        assert!(!dictionary.is_empty());
+        assert!(new_dictionary(&["cat", ""]).is_empty());
+    }
+
+    #[test]
+    fn test_remove_words() {
+        let dictionary = new_dictionary(&["dragon", "drag", "monkey", "keys"]);
+
+        for (password, expected, words) in &[
+            ("", "", &[][..]),
+            ("x7#q", "x7#q", &[][..]),
+            ("1DRAGON!", "1!", &["dragon"][..]),
+            ("dragonmonkey", "", &["dragon", "monkey"][..]),
+            ("monkeys", "s", &["monkey"][..]),
+            ("dra9gon", "dra9gon", &[][..]),
+            ("mondragonkey", "", &["dragon", "monkey"][..]),
+        ] {
+            let mut pw: Vec<char> = password.chars().collect();
+            let removed = dictionary.remove_words(&mut pw, None);
+            assert_eq!(*expected, pw.iter().collect::<String>(), "{password}");
+            assert_eq!(*words, removed.as_slice(), "{password}");
+        }
+    }
+}
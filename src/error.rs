@@ -0,0 +1,74 @@
+//! The errors of the calculation of a [`PasswordInfo`](crate::PasswordInfo).
+
+use core::fmt;
+
+/// The error if a password can not be analyzed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PasswordError {
+    /// The password is empty.
+    Empty,
+    /// The password is shorter than the contained minimum amount of characters.
+    TooShort(usize),
+    /// The PIN contains a character that is not an ASCII digit.
+    NotNumeric,
+    /// The bits of a [`ThresholdTable`](crate::ThresholdTable) are not
+    /// strictly ascending.
+    UnsortedThresholds,
+}
+
+impl fmt::Display for PasswordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the password is empty"),
+            Self::TooShort(min) => write!(f, "the password is shorter than {min} characters"),
+            Self::NotNumeric => write!(f, "the PIN contains a character that is not a digit"),
+            Self::UnsortedThresholds => write!(f, "the thresholds are not sorted ascending"),
+        }
+    }
+}
+
+impl core::error::Error for PasswordError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PasswordError, PasswordInfo};
+
+    #[test]
+    fn test_try_for_password() {
+        assert_eq!(
+            Some(PasswordError::Empty),
+            PasswordInfo::try_for_password("").err()
+        );
+
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            let info = PasswordInfo::try_for_password("letmein").expect("password is valid");
+            assert_eq!(7, info.length());
+        }
+
+        let builder = PasswordInfo::builder().min_length(8);
+        assert_eq!(
+            Some(PasswordError::Empty),
+            builder.try_for_password("").err()
+        );
+        assert_eq!(
+            Some(PasswordError::TooShort(8)),
+            builder.try_for_password("letmein").err()
+        );
+        // The length before stripping is checked
+        assert!(builder.try_for_password("password").is_ok());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("the password is empty", PasswordError::Empty.to_string());
+        assert_eq!(
+            "the password is shorter than 8 characters",
+            PasswordError::TooShort(8).to_string()
+        );
+        assert_eq!(
+            "the thresholds are not sorted ascending",
+            PasswordError::UnsortedThresholds.to_string()
+        );
+    }
+}
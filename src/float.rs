@@ -0,0 +1,84 @@
+//! Floating point functions that are available with and without `std`.
+//!
+//! Without the feature `std` the functions of the `libm` crate are used.
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use libm as imp;
+
+/// The functions of the floating point types of `std`.
+#[cfg(feature = "std")]
+mod imp {
+    /// Calculates the base 2 logarithm of `x`.
+    pub fn log2(x: f64) -> f64 {
+        x.log2()
+    }
+
+    /// Calculates the square root of `x`.
+    pub fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
+
+    /// Calculates the smallest integer greater than or equal to `x`.
+    pub fn ceil(x: f64) -> f64 {
+        x.ceil()
+    }
+
+    /// Calculates `2 ^ x`.
+    pub fn exp2(x: f64) -> f64 {
+        x.exp2()
+    }
+}
+
+/// Stand-ins if neither the feature `std` nor `libm` is activated. The crate
+/// root already fails to compile with a clear message in this case, these
+/// only keep the compiler from reporting follow-up errors.
+#[cfg(not(any(feature = "std", feature = "libm")))]
+mod imp {
+    /// Never called, the crate does not compile.
+    pub fn log2(x: f64) -> f64 {
+        x
+    }
+
+    /// Never called, the crate does not compile.
+    pub fn sqrt(x: f64) -> f64 {
+        x
+    }
+
+    /// Never called, the crate does not compile.
+    pub fn ceil(x: f64) -> f64 {
+        x
+    }
+
+    /// Never called, the crate does not compile.
+    pub fn exp2(x: f64) -> f64 {
+        x
+    }
+}
+
+/// Calculates the logarithm of `x` with respect to the given base.
+///
+/// The logarithm is calculated with `log_2(x) / log_2(base)`, so it is exact
+/// for the base 2.
+pub fn log(x: f64, base: f64) -> f64 {
+    log2(x) / log2(base)
+}
+
+/// Calculates the base 2 logarithm of `x`.
+pub fn log2(x: f64) -> f64 {
+    imp::log2(x)
+}
+
+/// Calculates the square root of `x`.
+pub fn sqrt(x: f64) -> f64 {
+    imp::sqrt(x)
+}
+
+/// Calculates the smallest integer greater than or equal to `x`.
+pub fn ceil(x: f64) -> f64 {
+    imp::ceil(x)
+}
+
+/// Calculates `2 ^ x`.
+pub fn exp2(x: f64) -> f64 {
+    imp::exp2(x)
+}
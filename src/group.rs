@@ -0,0 +1,73 @@
+//! The kinds of character groups.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The kind of a character group, that contributes to the base of a password.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GroupKind {
+    /// The replace characters, see [`REPLACE_CHARS`](crate::REPLACE_CHARS).
+    Replace,
+    /// The separator characters, see [`SEPARATOR_CHARS`](crate::SEPARATOR_CHARS).
+    Separator,
+    /// The other special characters, see
+    /// [`OTHER_SPECIAL_CHARS`](crate::OTHER_SPECIAL_CHARS).
+    OtherSpecial,
+    /// The lower characters, see [`LOWER_CHARS`](crate::LOWER_CHARS).
+    Lower,
+    /// The upper characters, see [`UPPER_CHARS`](crate::UPPER_CHARS).
+    Upper,
+    /// The digits, see [`DIGIT_CHARS`](crate::DIGIT_CHARS).
+    Digit,
+    /// The letters beyond ASCII, see
+    /// [`UNICODE_LETTER_BASE`](crate::UNICODE_LETTER_BASE).
+    UnicodeLetter,
+    /// The emoji, see [`EMOJI_BASE`](crate::EMOJI_BASE).
+    Emoji,
+    /// The printable ASCII characters that are not part of any other group,
+    /// see [`has_unclassified`](crate::PasswordInfo::has_unclassified).
+    Unclassified,
+    /// The bytes beyond ASCII, see
+    /// [`EXTENDED_BYTE_BASE`](crate::EXTENDED_BYTE_BASE).
+    ExtendedByte,
+}
+
+impl GroupKind {
+    /// All kinds of character groups in the order of their declaration.
+    pub const ALL: &'static [Self] = &[
+        Self::Replace,
+        Self::Separator,
+        Self::OtherSpecial,
+        Self::Lower,
+        Self::Upper,
+        Self::Digit,
+        Self::UnicodeLetter,
+        Self::Emoji,
+        Self::Unclassified,
+        Self::ExtendedByte,
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GroupKind, PasswordInfo};
+
+    #[test]
+    fn test_has_group() {
+        let info = PasswordInfo::for_password("!_\"aA0é😀`");
+        for &kind in GroupKind::ALL {
+            assert_eq!(kind != GroupKind::ExtendedByte, info.has_group(kind));
+        }
+        assert_eq!(info.has_lower_character(), info.has_group(GroupKind::Lower));
+        assert_eq!(GroupKind::ALL.len() - 1, info.present_groups().len());
+
+        let info = PasswordInfo::for_bytes(&[b'a', 0xFF]);
+        assert_eq!(
+            vec![GroupKind::Lower, GroupKind::ExtendedByte],
+            info.present_groups()
+        );
+
+        assert!(PasswordInfo::for_password("").present_groups().is_empty());
+    }
+}
@@ -0,0 +1,261 @@
+//! The incremental scoring of a password while it is typed.
+
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::fmt;
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    is_emoji, is_printable_ascii, CharsetConfig, GroupKind, Groups, PasswordInfo,
+    PasswordInfoBuilder, SequenceSet,
+};
+
+/// A scorer for a live password field, which is updated on every keystroke
+/// with [`push`](IncrementalScorer::push) and [`pop`](IncrementalScorer::pop).
+///
+/// The length, the character groups and the base of the typed password are
+/// updated incrementally in constant time. The stripping of palindromes,
+/// common sequences, dictionary words, repeats and all other patterns depends
+/// on the whole password, so the [`info`](IncrementalScorer::info) and the
+/// [`entropy`](IncrementalScorer::get_entropy) are still recomputed on demand.
+/// The common sequences are only prepared once, when the scorer is created.
+///
+/// If the feature `zeroize` is activated, the typed password is overwritten
+/// with zeros when the scorer is dropped.
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::{GroupKind, IncrementalScorer};
+///
+/// let mut scorer = IncrementalScorer::new();
+/// for c in "Secret".chars() {
+///     scorer.push(c);
+/// }
+/// assert_eq!(52, scorer.base());
+///
+/// let _ = scorer.pop();
+/// scorer.push('1');
+/// assert!(scorer.has_group(GroupKind::Digit));
+/// assert_eq!(6, scorer.info().length());
+/// ```
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct IncrementalScorer<'a> {
+    /// The builder to calculate the info with.
+    #[cfg_attr(feature = "zeroize", zeroize(skip))]
+    builder: PasswordInfoBuilder<'a>,
+    /// The prepared common sequences of the builder.
+    #[cfg_attr(feature = "zeroize", zeroize(skip))]
+    sequences: Cow<'static, SequenceSet>,
+    /// The typed password.
+    password: Vec<char>,
+    /// The amount of characters of the typed password in each group, indexed
+    /// like [`GroupKind::ALL`](GroupKind::ALL).
+    counts: [usize; GroupKind::ALL.len()],
+}
+
+impl<'a> IncrementalScorer<'a> {
+    /// Creates a new scorer for an empty password that uses the default
+    /// builder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_builder(PasswordInfoBuilder::default())
+    }
+
+    /// Creates a new scorer for an empty password that uses the configuration
+    /// of the given builder.
+    #[must_use]
+    pub fn with_builder(builder: PasswordInfoBuilder<'a>) -> Self {
+        let sequences = builder.sequence_chars();
+
+        Self {
+            builder,
+            sequences,
+            password: Vec::new(),
+            counts: [0; GroupKind::ALL.len()],
+        }
+    }
+
+    /// Appends the given character to the password.
+    pub fn push(&mut self, c: char) {
+        let charset = &self.builder.charset;
+        for (count, &kind) in self.counts.iter_mut().zip(GroupKind::ALL) {
+            if contains(kind, c, charset) {
+                *count += 1;
+            }
+        }
+
+        self.password.push(c);
+    }
+
+    /// Removes the last character of the password and returns it, or `None`
+    /// if the password is empty.
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.password.pop()?;
+
+        let charset = &self.builder.charset;
+        for (count, &kind) in self.counts.iter_mut().zip(GroupKind::ALL) {
+            if contains(kind, c, charset) {
+                *count -= 1;
+            }
+        }
+
+        Some(c)
+    }
+
+    /// Removes all characters of the password.
+    pub fn clear(&mut self) {
+        #[cfg(feature = "zeroize")]
+        self.password.zeroize();
+
+        self.password.clear();
+        self.counts = [0; GroupKind::ALL.len()];
+    }
+
+    /// The length of the typed password before stripping.
+    #[must_use]
+    pub fn length(&self) -> usize {
+        self.password.len()
+    }
+
+    /// True, if no character was typed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.password.is_empty()
+    }
+
+    /// True, if the typed password contains at least one character of the
+    /// given group.
+    #[must_use]
+    pub const fn has_group(&self, kind: GroupKind) -> bool {
+        self.counts[kind as usize] > 0
+    }
+
+    /// The base of the typed password before stripping, calculated from the
+    /// groups it contains.
+    #[must_use]
+    pub fn base(&self) -> u16 {
+        let groups = Groups {
+            replace: self.has_group(GroupKind::Replace),
+            separator: self.has_group(GroupKind::Separator),
+            other_special: self.has_group(GroupKind::OtherSpecial),
+            lower: self.has_group(GroupKind::Lower),
+            upper: self.has_group(GroupKind::Upper),
+            digit: self.has_group(GroupKind::Digit),
+            unicode_letter: self.has_group(GroupKind::UnicodeLetter),
+            emoji: self.has_group(GroupKind::Emoji),
+            unclassified: self.has_group(GroupKind::Unclassified),
+            ..Groups::default()
+        };
+
+        groups.base(&self.builder.charset, self.builder.base_model)
+    }
+
+    /// Calculates the [`PasswordInfo`](PasswordInfo) of the typed password.
+    ///
+    /// This runs all stripping passes on the whole password.
+    #[must_use]
+    pub fn info(&self) -> PasswordInfo {
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut password: String = self.password.iter().collect();
+        let info = self.builder.analyze_password(&password, &self.sequences);
+
+        #[cfg(feature = "zeroize")]
+        password.zeroize();
+
+        info
+    }
+
+    /// Calculates the entropy of the typed password, see
+    /// [`PasswordInfo::get_entropy`](PasswordInfo::get_entropy).
+    ///
+    /// This runs all stripping passes on the whole password.
+    #[must_use]
+    pub fn get_entropy(&self) -> f64 {
+        self.info().get_entropy()
+    }
+}
+
+impl Default for IncrementalScorer<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for IncrementalScorer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IncrementalScorer")
+            .field("builder", &self.builder)
+            .field("length", &self.password.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// True, if the given character is part of the group of the given kind, like
+/// it is classified while calculating the base of a password.
+fn contains(kind: GroupKind, c: char, charset: &CharsetConfig<'_>) -> bool {
+    match kind {
+        GroupKind::Replace => charset.replace.contains(c),
+        GroupKind::Separator => charset.separator.contains(c),
+        GroupKind::OtherSpecial => charset.other_special.contains(c),
+        GroupKind::Lower => charset.lower.contains(c),
+        GroupKind::Upper => charset.upper.contains(c),
+        GroupKind::Digit => charset.digit.contains(c),
+        GroupKind::UnicodeLetter => !c.is_ascii() && c.is_alphabetic() && !charset.contains(c),
+        GroupKind::Emoji => is_emoji(c) && !charset.contains(c),
+        GroupKind::Unclassified => is_printable_ascii(c) && !charset.contains(c),
+        GroupKind::ExtendedByte => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GroupKind, IncrementalScorer, PasswordInfoBuilder};
+
+    #[test]
+    fn test_push_pop() {
+        let mut scorer = IncrementalScorer::new();
+        assert!(scorer.is_empty());
+        assert_eq!(0, scorer.base());
+        assert_eq!(None, scorer.pop());
+
+        for c in "aB3!".chars() {
+            scorer.push(c);
+        }
+        assert_eq!(4, scorer.length());
+        assert_eq!(26 + 26 + 10 + 5, scorer.base());
+
+        assert_eq!(Some('!'), scorer.pop());
+        assert!(!scorer.has_group(GroupKind::Replace));
+        assert_eq!(26 + 26 + 10, scorer.base());
+
+        scorer.clear();
+        assert!(scorer.is_empty());
+        assert!(!scorer.has_group(GroupKind::Lower));
+    }
+
+    #[test]
+    fn test_matches_full_recompute() {
+        let builder = PasswordInfoBuilder::new().with_extra_sequences(&["acme"]);
+        let mut scorer = IncrementalScorer::with_builder(builder.clone());
+
+        let mut typed = String::new();
+        for c in "acme Pässwörd_1990!".chars() {
+            scorer.push(c);
+            typed.push(c);
+
+            let expected = builder.for_password(&typed);
+            let actual = scorer.info();
+            assert_eq!(expected.length(), actual.length());
+            assert_eq!(expected.base(), actual.base());
+            assert!((expected.get_entropy() - scorer.get_entropy()).abs() < f64::EPSILON);
+
+            let unstripped = PasswordInfoBuilder::new()
+                .strip_palindrome(false)
+                .strip_sequences(false)
+                .strip_repeats(false)
+                .for_password(&typed);
+            assert_eq!(unstripped.base(), scorer.base());
+        }
+    }
+}
@@ -0,0 +1,228 @@
+//! The detection of keyboard walks like `qazwsx`.
+
+use alloc::vec::Vec;
+
+/// The minimum amount of physically adjacent keys that are considered a
+/// keyboard walk.
+///
+/// Shorter walks of at least [`MIN_CHAINED_WALK_LENGTH`](MIN_CHAINED_WALK_LENGTH)
+/// keys are only considered a keyboard walk, if they are directly followed or
+/// preceded by another walk, like `qaz` and `wsx` in `qazwsx`.
+pub const MIN_WALK_LENGTH: usize = 4;
+
+/// The minimum amount of physically adjacent keys that are considered a
+/// keyboard walk, if the walk is chained with another walk like `qazwsx`.
+pub const MIN_CHAINED_WALK_LENGTH: usize = 3;
+
+/// A keyboard layout to detect keyboard walks like `qazwsx` or `1qaz`.
+///
+/// The layout is modeled as rows of keys. Each row has a horizontal offset in
+/// key widths, because the rows of most keyboards are staggered. Two keys are
+/// adjacent, if their rows differ by at most one and their horizontal
+/// positions differ by at most one key width. Only the unshifted characters of
+/// the keys are modeled and the detection ignores the case of letters.
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::{KeyboardLayout, PasswordInfo};
+///
+/// let info = PasswordInfo::builder()
+///     .detect_keyboard_walks(KeyboardLayout::qwerty())
+///     .for_password("1qaz2wsx");
+/// # #[cfg(not(feature = "large-wordlist"))]
+/// assert_eq!(2, info.length());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyboardLayout {
+    /// The character, row and horizontal position of each key.
+    keys: Vec<(char, usize, f64)>,
+}
+
+impl KeyboardLayout {
+    /// Creates a new layout from the given rows, from top to bottom. Each row
+    /// consists of its unshifted characters from left to right and its
+    /// horizontal offset in key widths.
+    #[must_use]
+    pub fn new(rows: &[(&str, f64)]) -> Self {
+        let keys = rows
+            .iter()
+            .enumerate()
+            .flat_map(|(row, &(keys, offset))| {
+                keys.chars()
+                    .enumerate()
+                    .map(move |(column, key)| (key, row, offset + column as f64))
+            })
+            .collect();
+
+        Self { keys }
+    }
+
+    /// The US QWERTY layout.
+    #[must_use]
+    pub fn qwerty() -> Self {
+        Self::new(&[
+            ("1234567890-=", 0.0),
+            ("qwertyuiop[]\\", 0.5),
+            ("asdfghjkl;'", 0.75),
+            ("zxcvbnm,./", 1.25),
+        ])
+    }
+
+    /// The German QWERTZ layout.
+    #[must_use]
+    pub fn qwertz() -> Self {
+        Self::new(&[
+            ("1234567890ß´", 0.0),
+            ("qwertzuiopü+", 0.5),
+            ("asdfghjklöä#", 0.75),
+            ("<yxcvbnm,.-", 0.25),
+        ])
+    }
+
+    /// The French AZERTY layout.
+    #[must_use]
+    pub fn azerty() -> Self {
+        Self::new(&[
+            ("&é\"'(-è_çà)=", 0.0),
+            ("azertyuiop^$", 0.5),
+            ("qsdfghjklmù*", 0.75),
+            ("<wxcvbn,;:!", 0.25),
+        ])
+    }
+
+    /// The row and horizontal position of the key of the given character.
+    fn position(&self, c: char) -> Option<(usize, f64)> {
+        let c = c.to_lowercase().next().unwrap_or(c);
+        self.keys
+            .iter()
+            .find(|&&(key, _, _)| key == c)
+            .map(|&(_, row, x)| (row, x))
+    }
+
+    /// True, if the keys of both characters are different but physically
+    /// adjacent.
+    fn is_adjacent(&self, a: char, b: char) -> bool {
+        match (self.position(a), self.position(b)) {
+            (Some((row_a, x_a)), Some((row_b, x_b))) => {
+                let same_key = row_a == row_b && (x_a - x_b).abs() < 0.5;
+                !same_key && row_a.abs_diff(row_b) <= 1 && (x_a - x_b).abs() <= 1.0
+            }
+            _ => false,
+        }
+    }
+
+    /// Reduces each keyboard walk to its first character in place. See
+    /// [`MIN_WALK_LENGTH`](MIN_WALK_LENGTH) for the walks that are detected.
+    ///
+    /// If `positions` are given, the entries of each removed character are
+    /// removed from them, too.
+    pub(crate) fn remove_walks(
+        &self,
+        password: &mut Vec<char>,
+        mut positions: Option<&mut Vec<usize>>,
+    ) {
+        let len = password.len();
+
+        // The maximal runs of adjacent keys.
+        let mut runs = Vec::new();
+        let mut start = 0;
+        while start < len {
+            let mut end = start + 1;
+            while end < len && self.is_adjacent(password[end - 1], password[end]) {
+                end += 1;
+            }
+            runs.push((start, end));
+            start = end;
+        }
+
+        let is_chainable = |run: Option<&(usize, usize)>| {
+            run.is_some_and(|&(start, end)| end - start >= MIN_CHAINED_WALK_LENGTH)
+        };
+
+        let mut write = 0;
+        for (index, &(start, end)) in runs.iter().enumerate() {
+            let run_length = end - start;
+            let is_walk = run_length >= MIN_WALK_LENGTH
+                || (run_length >= MIN_CHAINED_WALK_LENGTH
+                    && (is_chainable(index.checked_sub(1).and_then(|i| runs.get(i)))
+                        || is_chainable(runs.get(index + 1))));
+
+            let keep = if is_walk { start + 1 } else { end };
+            password.copy_within(start..keep, write);
+            if let Some(positions) = positions.as_deref_mut() {
+                positions.copy_within(start..keep, write);
+            }
+            write += keep - start;
+        }
+
+        password.truncate(write);
+        if let Some(positions) = positions {
+            positions.truncate(write);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::KeyboardLayout;
+
+    #[test]
+    fn test_remove_walks() {
+        let layout = KeyboardLayout::qwerty();
+
+        let mut password: Vec<char> = "1qaz2wsx".chars().collect();
+        layout.remove_walks(&mut password, None);
+        let expected: Vec<char> = "12".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "Secret!qazwsx".chars().collect();
+        layout.remove_walks(&mut password, None);
+        let expected: Vec<char> = "Secret!qw".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "Secret!zxcvbnm".chars().collect();
+        layout.remove_walks(&mut password, None);
+        let expected: Vec<char> = "Secret!z".chars().collect();
+        assert_eq!(expected, password);
+
+        // The case is ignored
+        let mut password: Vec<char> = "XqAzWsX".chars().collect();
+        layout.remove_walks(&mut password, None);
+        let expected: Vec<char> = "XqW".chars().collect();
+        assert_eq!(expected, password);
+
+        // A short walk is only detected if it is chained with another walk
+        let mut password: Vec<char> = "qaz!".chars().collect();
+        layout.remove_walks(&mut password, None);
+        let expected: Vec<char> = "qaz!".chars().collect();
+        assert_eq!(expected, password);
+
+        // Walks that are too short and non-adjacent keys are untouched
+        let mut password: Vec<char> = "Tr0ub4dor&3".chars().collect();
+        layout.remove_walks(&mut password, None);
+        let expected: Vec<char> = "Tr0ub4dor&3".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = Vec::new();
+        layout.remove_walks(&mut password, None);
+        assert!(password.is_empty());
+    }
+
+    #[test]
+    fn test_layouts() {
+        let mut password: Vec<char> = "qayxsw".chars().collect();
+        KeyboardLayout::qwertz().remove_walks(&mut password, None);
+        let expected: Vec<char> = "q".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "aqwzsx".chars().collect();
+        KeyboardLayout::azerty().remove_walks(&mut password, None);
+        let expected: Vec<char> = "az".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "abcd".chars().collect();
+        KeyboardLayout::new(&[("abcd", 0.0)]).remove_walks(&mut password, None);
+        let expected: Vec<char> = "a".chars().collect();
+        assert_eq!(expected, password);
+    }
+}
@@ -11,7 +11,15 @@
 //!
 //! let password = "ThisIsASecret";
 //! let entropy = PasswordInfo::for_password(password).get_entropy();
+//!
+//! // Or shorter
+//! assert_eq!(entropy, pw_entropy::entropy_bits(password));
 //! ```
+//!
+//! ## `no_std`
+//! The crate can be used without `std` by disabling the default feature `std`
+//! and activating the feature `libm`, which is used to calculate the logarithms.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
@@ -39,7 +47,6 @@
     clippy::print_stdout,
     clippy::rest_pat_in_fully_bound_structs,
     clippy::str_to_string,
-    clippy::string_to_string,
     clippy::todo,
     clippy::unimplemented,
     clippy::unneeded_field_pattern,
@@ -50,13 +57,85 @@
 #![allow(
     clippy::suboptimal_flops,
     clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
     clippy::cast_sign_loss,
     clippy::cast_possible_wrap,
     clippy::module_name_repetitions
 )]
 
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("Either the feature `std` or `libm` must be activated.");
+
+use alloc::{borrow::Cow, string::String, vec, vec::Vec};
+use core::{cmp::Ordering, convert::Infallible, fmt, str::FromStr, time::Duration};
+#[cfg(feature = "std")]
+use std::{collections::HashMap, sync::OnceLock};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 #[cfg(feature = "zeroize")]
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use dictionary::Dictionary;
+use sequence::SequenceSet;
+
+#[cfg(feature = "audit")]
+pub mod audit;
+mod base_model;
+mod breach;
+mod builder;
+mod case;
+mod charset;
+mod composition;
+mod date;
+mod dictionary;
+mod error;
+mod float;
+mod group;
+mod incremental;
+mod keyboard;
+mod passphrase;
+mod pattern;
+mod penalty;
+mod pin;
+mod policy;
+mod report;
+#[cfg(feature = "unicode-script")]
+mod script;
+mod sequence;
+mod strength;
+mod trace;
+pub mod transform;
+mod warning;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use base_model::BaseModel;
+pub use breach::BreachOracle;
+#[cfg(feature = "std")]
+pub use breach::BreachSet;
+pub use builder::{ForPasswords, PasswordInfoBuilder};
+pub use charset::CharsetConfig;
+pub use composition::GroupComposition;
+pub use error::PasswordError;
+pub use group::GroupKind;
+pub use incremental::IncrementalScorer;
+pub use keyboard::{KeyboardLayout, MIN_CHAINED_WALK_LENGTH, MIN_WALK_LENGTH};
+pub use passphrase::PassphraseInfo;
+pub use pattern::{Component, PatternEntropy};
+pub use penalty::Penalty;
+pub use pin::PinInfo;
+pub use policy::{Policy, PolicyResult, PolicyViolation};
+
+use policy::LowercasePassword;
+pub use report::PasswordReport;
+pub use strength::{PasswordStrength, ThresholdTable};
+pub use trace::{AnalysisTrace, TraceStage};
+#[cfg(feature = "unicode-script")]
+pub use unicode_script::Script;
+pub use warning::Warning;
 
 /// The list of the replace characters.
 pub const REPLACE_CHARS: &str = "!@$&*";
@@ -70,19 +149,125 @@ pub const LOWER_CHARS: &str = "abcdefghijklmnopqrstuvwxyz";
 pub const UPPER_CHARS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
 /// The list of digits.
 pub const DIGIT_CHARS: &str = "0123456789";
+/// The names and characters of all character groups, e.g. to list them in a
+/// user interface. The base of a password is the sum of the sizes of the
+/// groups it contains at least one character of.
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::{PasswordInfo, GROUPS};
+///
+/// let info = PasswordInfo::for_password("Tr0ub4dor&3");
+/// for (name, chars) in GROUPS {
+///     println!("{name}: {chars}");
+/// }
+/// ```
+pub const GROUPS: &[(&str, &str)] = &[
+    ("replace", REPLACE_CHARS),
+    ("separator", SEPARATOR_CHARS),
+    ("other special", OTHER_SPECIAL_CHARS),
+    ("lower", LOWER_CHARS),
+    ("upper", UPPER_CHARS),
+    ("digit", DIGIT_CHARS),
+];
+/// The sum of the sizes of all character groups in [`GROUPS`](GROUPS), the
+/// highest base a password of printable ASCII characters can have.
+pub const MAX_BASE: u16 = max_base();
+/// The base contribution of letters beyond ASCII like `é`, `ü` or `ß`.
+///
+/// There are far too many letters in Unicode to assume an attacker would try
+/// all of them. Instead the size of the most commonly used extension of the
+/// ASCII letters is used: the 62 letters `À` to `ÿ` of the Latin-1 supplement
+/// block, excluding `×` and `÷`.
+pub const UNICODE_LETTER_BASE: u16 = 62;
+
+/// The base contribution of emoji like `😀` or `🚀`.
+///
+/// Only emoji that are a single scalar value are detected, which are about
+/// 1400 in current Unicode versions. A character is an emoji, if it is part of
+/// one of the following blocks:
+///
+/// - `U+2600` to `U+26FF`: Miscellaneous Symbols
+/// - `U+2700` to `U+27BF`: Dingbats
+/// - `U+1F300` to `U+1F5FF`: Miscellaneous Symbols and Pictographs
+/// - `U+1F600` to `U+1F64F`: Emoticons
+/// - `U+1F680` to `U+1F6FF`: Transport and Map Symbols
+/// - `U+1F900` to `U+1F9FF`: Supplemental Symbols and Pictographs
+/// - `U+1FA70` to `U+1FAFF`: Symbols and Pictographs Extended-A
+pub const EMOJI_BASE: u16 = 1_400;
+
+/// The base contribution of the bytes beyond ASCII, the 128 bytes `0x80` to
+/// `0xFF`, see [`PasswordInfo::for_bytes`](PasswordInfo::for_bytes). The ASCII
+/// control bytes are part of this group, too.
+pub const EXTENDED_BYTE_BASE: u16 = 128;
+
+/// The stripped length a password should have at least, below it
+/// [`PasswordInfo::suggestions`](PasswordInfo::suggestions) suggests a longer
+/// password.
+pub const RECOMMENDED_LENGTH: usize = 12;
+
+/// The default stripped length above which a password is
+/// [`suspiciously long`](PasswordInfo::is_suspiciously_long), e.g. because
+/// text was pasted by accident.
+pub const MAX_REASONABLE_LENGTH: usize = 128;
+
+/// The guesses per second of an online attack against a service that throttles
+/// the login attempts to 100 guesses per hour.
+pub const ONLINE_THROTTLED_GUESS_RATE: f64 = 100.0 / 3600.0;
+/// The guesses per second of an online attack against a service without any
+/// throttling of the login attempts.
+pub const ONLINE_UNTHROTTLED_GUESS_RATE: f64 = 10.0;
+/// The guesses per second of an offline attack against passwords stored with
+/// a slow hash function like bcrypt, scrypt or Argon2.
+pub const OFFLINE_SLOW_HASH_GUESS_RATE: f64 = 1e4;
+/// The guesses per second of an offline attack with GPUs against passwords
+/// stored with a fast hash function like MD5 or SHA-1.
+pub const OFFLINE_GPU_GUESS_RATE: f64 = 1e10;
 
 /// The info about a password to calculate the password's entropy.
-#[derive(Clone, Copy, Debug)]
+///
+/// If the feature `serde` is activated, the info can be serialized and
+/// deserialized. The character frequencies of the stripped password are not
+/// serialized, so the [`Shannon entropy`](PasswordInfo::get_shannon_entropy)
+/// of a deserialized info is always `0.0`. The same applies to the
+/// [`per_char_entropy`](PasswordInfo::per_char_entropy), which is empty.
+///
+/// The info keeps a lowercase copy of the password to find the
+/// [`forbidden sequences`](Policy::forbidden_sequences) of a policy. The copy
+/// is neither part of the [`Debug`](fmt::Debug) output nor serialized.
+///
+/// If the feature `zeroize` is activated, all fields of the info are
+/// overwritten with zeros when it is dropped.
+///
+/// The info owns heap data like the character frequencies and the
+/// [`matched sequences`](PasswordInfo::matched_sequences), so since version
+/// `0.2.0` it is only [`Clone`](Clone) and no longer [`Copy`](Copy).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PasswordInfo {
     /// The stripped length of the password.
     length: usize,
+    /// The length of the password before stripping.
+    original_length: usize,
+    /// The length of the password without the stripped control characters.
+    printable_length: usize,
+    /// The base of the password before stripping.
+    original_base: u16,
+    /// The amount of distinct characters of the stripped password.
+    distinct_character_count: usize,
+    /// The amount of whitespace separated words of the stripped password.
+    word_count: usize,
+    /// The frequencies of the characters of the stripped password.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    frequencies: CharFrequencies,
     /// The calculated base of the password.
     base: u16,
     /// The password contains at least one replace character.
     has_replace: bool,
     /// The password contains at least one separator character.
-    has_seperator: bool,
+    has_separator: bool,
     /// The password contains at least one spacial character.
     has_other_special: bool,
     /// The password contains at least one lower character.
@@ -91,6 +276,66 @@ pub struct PasswordInfo {
     has_upper: bool,
     /// The password contains at least one digit.
     has_digit: bool,
+    /// The password contains at least one letter beyond ASCII.
+    has_unicode_letter: bool,
+    /// The password contains at least one emoji.
+    has_emoji: bool,
+    /// The password contains at least one printable ASCII character that is
+    /// not part of any group.
+    has_unclassified: bool,
+    /// The bytes contain at least one byte beyond ASCII.
+    has_extended_byte: bool,
+    /// The amount of characters of the stripped password in each group.
+    composition: GroupComposition,
+    /// A palindrome was removed from the password.
+    stripped_palindrome: bool,
+    /// At least one common sequence was removed from the password.
+    stripped_sequence: bool,
+    /// At least one repeating character or pattern was removed from the
+    /// password.
+    stripped_repeat: bool,
+    /// At least one control character was removed from the password.
+    stripped_control: bool,
+    /// The entropy in bits of the removed common sequences, see
+    /// [`Penalty::FixedBits`](Penalty::FixedBits), and dictionary words.
+    sequence_bits: f64,
+    /// The entropy in bits of the arrangement of the character groups, see
+    /// [`PasswordInfoBuilder::include_pattern_entropy`](PasswordInfoBuilder::include_pattern_entropy).
+    pattern_bits: f64,
+    /// The base below which the strength is capped at weak.
+    min_meaningful_base: u16,
+    /// The first character of the password is its only uppercase letter.
+    is_leading_capital_only: bool,
+    /// The stripped password only consists of whitespace and separators.
+    is_whitespace_only: bool,
+    /// A password that only consists of whitespace and separators has no
+    /// strength.
+    weak_whitespace_only: bool,
+    /// The distinct common sequences that were removed from the password.
+    matched_sequences: Vec<String>,
+    /// The stripped length is below the minimum reasonable length.
+    is_too_short: bool,
+    /// The stripped length is above the maximum reasonable length.
+    is_suspiciously_long: bool,
+    /// For each character of the original password, if it was kept while
+    /// stripping.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    kept: Vec<bool>,
+    /// The hashcat-style mask of the stripped password.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    mask: String,
+    /// The password after each stripping stage, if enabled.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    trace: Option<AnalysisTrace>,
+    /// The lowercase password before stripping, to find the forbidden
+    /// sequences of a policy.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    lowercase_password: LowercasePassword,
+    /// The distinct scripts of the letters of the stripped password.
+    #[cfg(feature = "unicode-script")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "zeroize", zeroize(skip))]
+    scripts: Vec<Script>,
 }
 
 impl PasswordInfo {
@@ -102,56 +347,435 @@ impl PasswordInfo {
     ///
     /// If overwriting of the local copy with zeros is needed, after the
     /// calculation is done, activate the feature `zeroize`.
+    ///
+    /// The empty password is guaranteed to have a base and a length of `0`,
+    /// an entropy of `0.0` bits and no character group at all.
+    ///
+    /// Use a [`PasswordInfoBuilder`](PasswordInfoBuilder) to customize the
+    /// calculation.
     #[must_use]
     pub fn for_password(password: &str) -> Self {
-        //let password = password.to_owned();
-        let mut password: Vec<char> = password.chars().collect();
-        remove_palindrome(&mut password);
-        remove_common_sequences(&mut password);
-        remove_repeating_characters(&mut password);
-
-        let has_replace = REPLACE_CHARS.chars().any(|c| password.contains(&c));
-        let has_seperator = SEPARATOR_CHARS.chars().any(|c| password.contains(&c));
-        let has_other_special = OTHER_SPECIAL_CHARS.chars().any(|c| password.contains(&c));
-        let has_lower = LOWER_CHARS.chars().any(|c| password.contains(&c));
-        let has_upper = UPPER_CHARS.chars().any(|c| password.contains(&c));
-        let has_digits = DIGIT_CHARS.chars().any(|c| password.contains(&c));
+        PasswordInfoBuilder::default().for_password(password)
+    }
 
-        let length = password.len();
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for the given raw
+    /// bytes like [`for_password`](PasswordInfo::for_password), e.g. for
+    /// secrets that are not valid UTF-8.
+    ///
+    /// Each printable ASCII byte is classified like the ASCII character of the
+    /// same value, all other bytes are part of the extended group of
+    /// [`EXTENDED_BYTE_BASE`](EXTENDED_BYTE_BASE) bytes. Unlike in a password,
+    /// control bytes like `0x00` are kept, because they are a regular part of
+    /// binary secrets.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfo;
+    ///
+    /// let info = PasswordInfo::for_bytes(&[0x73, 0x65, 0x63, 0xFF, 0x00]);
+    /// assert!(info.has_extended_byte());
+    /// ```
+    #[must_use]
+    pub fn for_bytes(bytes: &[u8]) -> Self {
+        PasswordInfoBuilder::default().for_bytes(bytes)
+    }
 
-        #[cfg(feature = "zeroize")]
-        password.zeroize();
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for the given password
+    /// like [`for_password`](PasswordInfo::for_password), but returns
+    /// [`PasswordError::Empty`](PasswordError::Empty) for an empty password
+    /// instead of an info with an entropy of `0.0`.
+    ///
+    /// # Errors
+    /// Returns [`PasswordError::Empty`](PasswordError::Empty) if the password
+    /// is empty.
+    pub fn try_for_password(password: &str) -> Result<Self, PasswordError> {
+        PasswordInfoBuilder::default().try_for_password(password)
+    }
 
-        let mut base = 0;
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for the given password
+    /// like [`for_password`](PasswordInfo::for_password) and checks it against
+    /// the given [`BreachOracle`](BreachOracle).
+    ///
+    /// Returns the info and true, if the password is known to be breached. A
+    /// breached password should be rejected regardless of its entropy.
+    #[must_use]
+    pub fn for_password_checked<O: BreachOracle>(password: &str, oracle: &O) -> (Self, bool) {
+        (Self::for_password(password), oracle.is_breached(password))
+    }
 
-        if has_replace {
-            base += REPLACE_CHARS.len();
-        }
-        if has_seperator {
-            base += SEPARATOR_CHARS.len();
-        }
-        if has_other_special {
-            base += OTHER_SPECIAL_CHARS.len();
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for the given password
+    /// like [`for_password`](PasswordInfo::for_password), but counts grapheme
+    /// clusters instead of chars. See
+    /// [`PasswordInfoBuilder::for_password_graphemes`](PasswordInfoBuilder::for_password_graphemes)
+    /// for details.
+    ///
+    /// Requires the feature `unicode-segmentation`.
+    #[cfg(feature = "unicode-segmentation")]
+    #[must_use]
+    pub fn for_password_graphemes(password: &str) -> Self {
+        PasswordInfoBuilder::default().for_password_graphemes(password)
+    }
+
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for the given password
+    /// using the given character groups instead of the default ones.
+    #[must_use]
+    pub fn for_password_with_charset(password: &str, charset: &CharsetConfig<'_>) -> Self {
+        PasswordInfoBuilder::default()
+            .charset(charset.clone())
+            .for_password(password)
+    }
+
+    /// Calculates a new [`PassphraseInfo`](PassphraseInfo) for the given
+    /// passphrase, which scores each word of the word list as
+    /// `log_2(wordlist_len)` bits instead of per character.
+    #[must_use]
+    pub fn for_passphrase(passphrase: &str, wordlist: &[&str]) -> PassphraseInfo {
+        PassphraseInfo::new(passphrase, wordlist)
+    }
+
+    /// Calculates a new [`PinInfo`](PinInfo) for the given numeric PIN, which
+    /// has `log_2(10)` bits per digit or no entropy at all for a common PIN
+    /// like `1234`.
+    ///
+    /// # Errors
+    /// Returns [`PasswordError::Empty`](PasswordError::Empty) if the PIN is
+    /// empty and [`PasswordError::NotNumeric`](PasswordError::NotNumeric) if it
+    /// contains any character that is not an ASCII digit.
+    pub fn pin_entropy(pin: &str) -> Result<PinInfo, PasswordError> {
+        PinInfo::new(pin)
+    }
+
+    /// Calculates the combined entropy of a secret that consists of multiple
+    /// independent parts, like a PIN and a password, which is the sum of the
+    /// entropies of each part.
+    ///
+    /// This differs from the entropy of the concatenated parts, because the
+    /// parts are stripped separately. A concatenation can join the end of one
+    /// part and the start of the next part to a common sequence or a repeating
+    /// character that does not exist in either part, and the base of a
+    /// concatenation is shared by all characters instead of being the base of
+    /// the own part.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfo;
+    ///
+    /// let combined = PasswordInfo::combined_entropy(&["qwe", "rty"]);
+    /// let concatenated = PasswordInfo::for_password("qwerty").get_entropy();
+    /// assert!(combined > concatenated);
+    /// ```
+    #[must_use]
+    pub fn combined_entropy(parts: &[&str]) -> f64 {
+        Self::for_passwords(parts.iter().copied())
+            .map(|info| info.get_entropy())
+            .sum()
+    }
+
+    /// Creates a new [`PasswordInfoBuilder`](PasswordInfoBuilder) to customize
+    /// the calculation of the [`PasswordInfo`](PasswordInfo).
+    #[must_use]
+    pub fn builder<'a>() -> PasswordInfoBuilder<'a> {
+        PasswordInfoBuilder::default()
+    }
+
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for the given
+    /// characters of a password using the configuration of the given builder.
+    ///
+    /// If `bytes` is true, each character is a byte and all characters beyond
+    /// ASCII are part of the extended group instead of any other group.
+    fn analyze(
+        mut password: Vec<char>,
+        builder: &PasswordInfoBuilder<'_>,
+        sequences: &SequenceSet,
+        bytes: bool,
+    ) -> Self {
+        let original_length = password.len();
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut original = password.clone();
+        // The position in the original password of each remaining character.
+        let mut positions: Vec<usize> = (0..original_length).collect();
+
+        let mut stripped = Stripped::default();
+        let mut trace = builder.trace.then(AnalysisTrace::default);
+        trace::record(&mut trace, TraceStage::Input, &password);
+
+        if !bytes {
+            let mut chars = password.iter();
+            positions.retain(|_| chars.next().is_some_and(|c| !c.is_control()));
+            password.retain(|c| !c.is_control());
+            stripped.control = password.len() != original_length;
+            trace::record(&mut trace, TraceStage::Control, &password);
         }
-        if has_lower {
-            base += LOWER_CHARS.len();
+        let printable_length = password.len();
+        let is_leading_capital_only = case::is_leading_capital_only(&password);
+
+        strip_palindrome_and_case(
+            &mut password,
+            &mut positions,
+            builder,
+            &mut stripped,
+            &mut trace,
+        );
+
+        let mut matched_sequences = strip_sequences(
+            &mut password,
+            &mut positions,
+            builder,
+            sequences,
+            &mut stripped,
+            &mut trace,
+        );
+        strip_dictionary_words(
+            &mut password,
+            &mut positions,
+            builder,
+            &mut stripped,
+            &mut matched_sequences,
+            &mut trace,
+        );
+
+        strip_patterns(
+            &mut password,
+            &mut positions,
+            builder,
+            &mut stripped,
+            &mut trace,
+        );
+
+        let charset = &builder.charset;
+        let groups_of = |chars: &[char]| {
+            let mut groups = Groups::for_builder(builder);
+            for &c in chars {
+                if bytes && (!c.is_ascii() || c.is_ascii_control()) {
+                    groups.add_extended_byte();
+                } else {
+                    groups.add(c, charset);
+                }
+            }
+            groups
+        };
+        let groups = groups_of(&password);
+        let original_base = groups_of(&original).base(charset, builder.base_model);
+        let lowercase_password = LowercasePassword::new(&original);
+
+        let length = password.len();
+        let frequencies = CharFrequencies::from_chars(&password);
+        let kept = kept_characters(original_length, &positions);
+        let mask = password.iter().map(|&c| mask_symbol(c)).collect();
+        let is_whitespace_only = !password.is_empty()
+            && password
+                .iter()
+                .all(|&c| c.is_whitespace() || charset.separator.contains(c));
+
+        #[cfg(feature = "zeroize")]
+        {
+            password.zeroize();
+            original.zeroize();
         }
-        if has_upper {
-            base += UPPER_CHARS.len();
+
+        let mut info = Self::new(
+            original_length,
+            length,
+            frequencies,
+            &groups,
+            charset,
+            builder.base_model,
+            stripped,
+        );
+        info.printable_length = printable_length;
+        info.min_meaningful_base = builder.min_meaningful_base;
+        info.is_leading_capital_only = is_leading_capital_only;
+        info.is_whitespace_only = is_whitespace_only;
+        info.weak_whitespace_only = builder.weak_whitespace_only;
+        info.matched_sequences = matched_sequences;
+        info.is_too_short = length < builder.min_reasonable_length;
+        info.is_suspiciously_long = length > builder.max_reasonable_length;
+        info.kept = kept;
+        info.mask = mask;
+        info.original_base = original_base;
+        info.trace = trace;
+        info.lowercase_password = lowercase_password;
+        if builder.include_pattern_entropy {
+            info.pattern_bits = info.composition.arrangement_entropy();
         }
-        if has_digits {
-            base += DIGIT_CHARS.len();
+        info
+    }
+
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for each of the given
+    /// passwords like [`for_password`](PasswordInfo::for_password).
+    ///
+    /// The common sequences are only prepared once for all passwords, which
+    /// makes this faster than calling
+    /// [`for_password`](PasswordInfo::for_password) for each password, e.g.
+    /// when auditing large password lists.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfo;
+    ///
+    /// let passwords = ["password", "Tr0ub4dor&3"];
+    /// let entropies: Vec<f64> = PasswordInfo::for_passwords(passwords.iter().copied())
+    ///     .map(|info| info.get_entropy())
+    ///     .collect();
+    /// ```
+    pub fn for_passwords<'a, I>(passwords: I) -> impl Iterator<Item = Self> + 'a
+    where
+        I: IntoIterator<Item = &'a str>,
+        I::IntoIter: 'a,
+    {
+        let builder = PasswordInfoBuilder::default();
+        let sequences = builder.sequence_chars();
+
+        passwords.into_iter().map(move |password| {
+            Self::analyze(password.chars().collect(), &builder, &sequences, false)
+        })
+    }
+
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for each of the given
+    /// passwords in parallel like
+    /// [`for_passwords`](PasswordInfo::for_passwords). The infos are in the
+    /// order of the passwords.
+    ///
+    /// Requires the feature `rayon`.
+    #[cfg(feature = "rayon")]
+    #[must_use]
+    pub fn par_for_passwords(passwords: &[&str]) -> Vec<Self> {
+        use rayon::prelude::*;
+
+        let builder = PasswordInfoBuilder::default();
+        let sequences = builder.sequence_chars();
+
+        passwords
+            .par_iter()
+            .map(|password| Self::analyze(password.chars().collect(), &builder, &sequences, false))
+            .collect()
+    }
+
+    /// Calculates a new [`PasswordInfo`](PasswordInfo) for the given
+    /// characters in a single pass without collecting them.
+    ///
+    /// Only control characters and repeating characters like `aaaa` are
+    /// removed, because all other transformations need the whole password.
+    /// The palindrome, common sequences and repeating patterns like `abcabc`
+    /// are **not** removed, so the entropy might be higher than the one of
+    /// [`for_password`](PasswordInfo::for_password).
+    #[must_use]
+    pub fn for_chars<I: IntoIterator<Item = char>>(chars: I) -> Self {
+        let charset = CharsetConfig::default();
+        let mut groups = Groups::default();
+        let mut frequencies = CharFrequencies::default();
+        let mut original_length = 0;
+        let mut printable_length = 0;
+        let mut length = 0;
+        let mut previous = None;
+        let mut stripped = Stripped::default();
+        let mut kept = Vec::new();
+        let mut mask = String::new();
+        let mut is_whitespace_only = true;
+        let mut leading_capital = None;
+        let mut upper_count = 0;
+        let mut has_lower = false;
+        let mut lowercase_password = LowercasePassword::default();
+
+        for c in chars {
+            original_length += 1;
+            lowercase_password.push(c);
+            if c.is_control() {
+                stripped.control = true;
+                kept.push(false);
+                continue;
+            }
+            printable_length += 1;
+
+            let _ = leading_capital.get_or_insert_with(|| c.is_uppercase());
+            upper_count += usize::from(c.is_uppercase());
+            has_lower |= c.is_lowercase();
+
+            kept.push(previous != Some(c));
+            if previous == Some(c) {
+                stripped.repeat = true;
+                continue;
+            }
+            previous = Some(c);
+
+            groups.add(c, &charset);
+            frequencies.add(c);
+            mask.push_str(mask_symbol(c));
+            is_whitespace_only &= c.is_whitespace() || charset.separator.contains(c);
+            length += 1;
         }
 
+        let mut info = Self::new(
+            original_length,
+            length,
+            frequencies,
+            &groups,
+            &charset,
+            BaseModel::Linear,
+            stripped,
+        );
+        info.printable_length = printable_length;
+        info.kept = kept;
+        info.mask = mask;
+        info.lowercase_password = lowercase_password;
+        info.is_whitespace_only = is_whitespace_only && length > 0;
+        info.is_leading_capital_only =
+            leading_capital == Some(true) && upper_count == 1 && has_lower;
+        info
+    }
+
+    /// Creates a new [`PasswordInfo`](PasswordInfo) from the data of the
+    /// stripped password.
+    fn new(
+        original_length: usize,
+        length: usize,
+        frequencies: CharFrequencies,
+        groups: &Groups,
+        charset: &CharsetConfig<'_>,
+        model: BaseModel,
+        stripped: Stripped,
+    ) -> Self {
+        let base = groups.base(charset, model);
+
         Self {
             length,
-            base: base as u16,
-            has_replace,
-            has_seperator,
-            has_other_special,
-            has_lower,
-            has_upper,
-            has_digit: has_digits,
+            original_length,
+            printable_length: original_length,
+            original_base: base,
+            distinct_character_count: frequencies.distinct(),
+            word_count: groups.word_count,
+            frequencies,
+            base,
+            has_replace: groups.replace,
+            has_separator: groups.separator,
+            has_other_special: groups.other_special,
+            has_lower: groups.lower,
+            has_upper: groups.upper,
+            has_digit: groups.digit,
+            has_unicode_letter: groups.unicode_letter,
+            has_emoji: groups.emoji,
+            has_unclassified: groups.unclassified,
+            has_extended_byte: groups.extended_byte,
+            composition: groups.composition,
+            stripped_palindrome: stripped.palindrome,
+            stripped_sequence: stripped.sequence,
+            stripped_repeat: stripped.repeat,
+            stripped_control: stripped.control,
+            sequence_bits: stripped.sequence_bits,
+            pattern_bits: 0.0,
+            min_meaningful_base: 0,
+            is_leading_capital_only: false,
+            is_whitespace_only: false,
+            weak_whitespace_only: false,
+            matched_sequences: Vec::new(),
+            is_too_short: false,
+            is_suspiciously_long: length > MAX_REASONABLE_LENGTH,
+            kept: Vec::new(),
+            mask: String::new(),
+            trace: None,
+            lowercase_password: LowercasePassword::default(),
+            #[cfg(feature = "unicode-script")]
+            scripts: groups.scripts.clone(),
         }
     }
 
@@ -162,6 +786,196 @@ impl PasswordInfo {
         self.length
     }
 
+    /// The length of the password before removing common sequences, duplicate
+    /// characters and a possible palindrome.
+    #[must_use]
+    pub const fn original_length(&self) -> usize {
+        self.original_length
+    }
+
+    /// The ratio of the stripped [`length`](PasswordInfo::length) to the
+    /// [`original_length`](PasswordInfo::original_length) of the password.
+    ///
+    /// A ratio of `1.0` means nothing was removed, a ratio near `0.0` means
+    /// most of the password was predictable. An empty password has a ratio of
+    /// `1.0`.
+    #[must_use]
+    pub fn reduction_ratio(&self) -> f64 {
+        if self.original_length == 0 {
+            return 1.0;
+        }
+
+        self.length as f64 / self.original_length as f64
+    }
+
+    /// The amount of distinct characters of the stripped password. Unlike the
+    /// [`length`](PasswordInfo::length), `abab` only has two distinct
+    /// characters.
+    #[must_use]
+    pub const fn distinct_character_count(&self) -> usize {
+        self.distinct_character_count
+    }
+
+    /// The amount of words of the stripped password, split on whitespace.
+    /// Leading, trailing and consecutive whitespace does not count as empty
+    /// words, so ` correct  horse ` has two words.
+    ///
+    /// This does not influence the [`base`](PasswordInfo::base), but gives
+    /// passphrases a word count alongside the character entropy. See
+    /// [`for_passphrase`](PasswordInfo::for_passphrase) for an entropy based on
+    /// words.
+    #[must_use]
+    pub const fn word_count(&self) -> usize {
+        self.word_count
+    }
+
+    /// True, if the password was a palindrome like `abba` and its mirrored
+    /// half was removed.
+    #[must_use]
+    pub const fn stripped_palindrome(&self) -> bool {
+        self.stripped_palindrome
+    }
+
+    /// True, if at least one common sequence like `qwerty` was removed from
+    /// the password.
+    #[must_use]
+    pub const fn stripped_sequence(&self) -> bool {
+        self.stripped_sequence
+    }
+
+    /// True, if at least one repeating character like `aaa` or repeating
+    /// pattern like `abcabc` was removed from the password.
+    #[must_use]
+    pub const fn stripped_repeat(&self) -> bool {
+        self.stripped_repeat
+    }
+
+    /// True, if at least one control character like a tab `\t`, a newline
+    /// `\n` or a null `\0` was removed from the password.
+    ///
+    /// Control characters are not part of any group and can not be typed
+    /// into a regular password field, so they are removed before all other
+    /// transformations. They still count towards the
+    /// [`original length`](PasswordInfo::original_length).
+    #[must_use]
+    pub const fn stripped_control(&self) -> bool {
+        self.stripped_control
+    }
+
+    /// The base below which the [`strength`](PasswordInfo::strength) is capped
+    /// at [`Weak`](PasswordStrength::Weak), see
+    /// [`PasswordInfoBuilder::min_meaningful_base`](PasswordInfoBuilder::min_meaningful_base).
+    /// `0` if there is no such floor.
+    #[must_use]
+    pub const fn min_meaningful_base(&self) -> u16 {
+        self.min_meaningful_base
+    }
+
+    /// The hashcat-style mask of the stripped password, like `?u?l?l?l?d?d`
+    /// for `Pass12`, e.g. to analyze the structure of the passwords of a
+    /// dataset.
+    ///
+    /// Each character is replaced by the symbol of its class. The classes are
+    /// the ones of hashcat and independent of the character groups:
+    ///
+    /// | Symbol | Characters                                      |
+    /// |--------|-------------------------------------------------|
+    /// | `?l`   | the lowercase ASCII letters `a` to `z`          |
+    /// | `?u`   | the uppercase ASCII letters `A` to `Z`          |
+    /// | `?d`   | the digits `0` to `9`                           |
+    /// | `?s`   | the printable ASCII symbols including the space |
+    /// | `?b`   | every other character, like `é` or `😀`         |
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfo;
+    ///
+    /// assert_eq!("?u?l?d?d?s", PasswordInfo::for_password("Xy42!").mask());
+    /// ```
+    #[must_use]
+    pub fn mask(&self) -> &str {
+        &self.mask
+    }
+
+    /// The password after each stripping stage, or `None` if the trace was
+    /// not enabled with
+    /// [`PasswordInfoBuilder::trace`](PasswordInfoBuilder::trace).
+    ///
+    /// The trace contains the plaintext password, see
+    /// [`AnalysisTrace`](AnalysisTrace).
+    #[must_use]
+    pub const fn trace(&self) -> Option<&AnalysisTrace> {
+        self.trace.as_ref()
+    }
+
+    /// The distinct common sequences that were removed from the password in
+    /// the order they were removed, e.g. to tell the user to avoid `qwerty`.
+    ///
+    /// A sequence that was found in reverse or with leet substitutions is
+    /// reported as it is written in the list of common sequences.
+    #[must_use]
+    pub fn matched_sequences(&self) -> &[String] {
+        &self.matched_sequences
+    }
+
+    /// The distinct Unicode scripts of the letters of the stripped password
+    /// in the order of their appearance, like `[Latin, Cyrillic]` for
+    /// `pароль`. Digits, punctuation and other characters that are shared by
+    /// several scripts are ignored.
+    ///
+    /// See
+    /// [`PasswordInfoBuilder::script_aware_base`](PasswordInfoBuilder::script_aware_base)
+    /// to let each script contribute its own base.
+    ///
+    /// Requires the feature `unicode-script`.
+    #[cfg(feature = "unicode-script")]
+    #[must_use]
+    pub fn scripts_present(&self) -> Vec<Script> {
+        self.scripts.clone()
+    }
+
+    /// True, if the first character of the password is its only uppercase
+    /// letter and at least one lowercase letter follows, like `Password1`.
+    ///
+    /// This is checked before stripping. Such a capital adds the whole
+    /// uppercase group to the base, but almost no real entropy. See
+    /// [`PasswordInfoBuilder::dampen_leading_capital`](PasswordInfoBuilder::dampen_leading_capital)
+    /// to not credit it.
+    #[must_use]
+    pub const fn is_leading_capital_only(&self) -> bool {
+        self.is_leading_capital_only
+    }
+
+    /// True, if the stripped password is not empty and only consists of
+    /// whitespace and separator characters, like `"    "` or `"----"`.
+    ///
+    /// Such a password has a base of at least `5` by the separator group, but
+    /// is trivial to guess. See
+    /// [`PasswordInfoBuilder::weak_whitespace_only`](PasswordInfoBuilder::weak_whitespace_only)
+    /// to rate it as [`VeryWeak`](PasswordStrength::VeryWeak).
+    #[must_use]
+    pub const fn is_whitespace_only(&self) -> bool {
+        self.is_whitespace_only
+    }
+
+    /// True, if the stripped length is below the minimum reasonable length,
+    /// see
+    /// [`PasswordInfoBuilder::min_reasonable_length`](PasswordInfoBuilder::min_reasonable_length).
+    /// Such a password is rated at most [`Weak`](PasswordStrength::Weak).
+    #[must_use]
+    pub const fn is_too_short(&self) -> bool {
+        self.is_too_short
+    }
+
+    /// True, if the stripped length is above the maximum reasonable length,
+    /// [`MAX_REASONABLE_LENGTH`](MAX_REASONABLE_LENGTH) by default. Such a
+    /// password might be pasted text or the result of an accidental key
+    /// repeat and is rated at most [`Strong`](PasswordStrength::Strong).
+    #[must_use]
+    pub const fn is_suspiciously_long(&self) -> bool {
+        self.is_suspiciously_long
+    }
+
     /// The calculated base of the password
     #[must_use]
     pub const fn base(&self) -> u16 {
@@ -171,176 +985,2829 @@ impl PasswordInfo {
     /// True, if the password contains replace characters.
     #[must_use]
     pub const fn has_replace_character(&self) -> bool {
-        self.has_replace
+        self.has_group(GroupKind::Replace)
+    }
+
+    /// True, if the password contains separator characters.
+    #[must_use]
+    pub const fn has_separator_character(&self) -> bool {
+        self.has_group(GroupKind::Separator)
     }
 
-    /// True, if the password contains seperator characters.
+    /// True, if the password contains separator characters.
+    #[deprecated(note = "use `has_separator_character` instead")]
     #[must_use]
     pub const fn has_seperator_character(&self) -> bool {
-        self.has_seperator
+        self.has_separator_character()
     }
 
     /// True, if the password contains other special characters that are neither
-    /// replace nor seperator characters.
+    /// replace nor separator characters.
     #[must_use]
     pub const fn has_other_special_character(&self) -> bool {
-        self.has_other_special
+        self.has_group(GroupKind::OtherSpecial)
     }
 
     /// True, if the password contains at least one lower character.
     #[must_use]
     pub const fn has_lower_character(&self) -> bool {
-        self.has_lower
+        self.has_group(GroupKind::Lower)
     }
 
     /// True, if the password contains at least one upper character.
     #[must_use]
     pub const fn has_upper_character(&self) -> bool {
-        self.has_upper
+        self.has_group(GroupKind::Upper)
     }
 
     /// True, if the password contains at least one digit.
     #[must_use]
     pub const fn has_digit(&self) -> bool {
-        self.has_digit
+        self.has_group(GroupKind::Digit)
     }
 
-    /// Calculates the entropy of the password based on: `log_2(base ^ length)`.
+    /// True, if the password contains at least one letter beyond ASCII like
+    /// `é`, `ü` or `ß` that is not part of any other group. See
+    /// [`UNICODE_LETTER_BASE`](UNICODE_LETTER_BASE) for its default
+    /// contribution to the base.
     #[must_use]
-    pub fn get_entropy(&self) -> f64 {
-        log_power(f64::from(self.base), self.length, 2.0)
+    pub const fn has_unicode_letter(&self) -> bool {
+        self.has_group(GroupKind::UnicodeLetter)
     }
-}
 
-/// Removes repeating characters from the password in place.
-fn remove_repeating_characters(password: &mut Vec<char>) {
-    password.dedup()
-}
+    /// True, if the password contains at least one emoji like `😀` that is
+    /// not part of any other group. See [`EMOJI_BASE`](EMOJI_BASE) for the
+    /// detected emoji and their contribution to the base.
+    #[must_use]
+    pub const fn has_emoji(&self) -> bool {
+        self.has_group(GroupKind::Emoji)
+    }
 
-/// Removes the palindrome if it exists. If the password is a palindrome the
-/// half of the palindrome will be removed in place.
-fn remove_palindrome(password: &mut Vec<char>) {
-    let half = password.len() / 2 + password.len() % 2;
+    /// True, if the password contains at least one printable ASCII character,
+    /// that is in the range from the space `' '` to the tilde `'~'`, which is
+    /// not part of any other group, like the backtick `` ` ``. All printable
+    /// ASCII characters not part of any group contribute to the base together.
+    #[must_use]
+    pub const fn has_unclassified(&self) -> bool {
+        self.has_group(GroupKind::Unclassified)
+    }
 
-    let forwards = password.iter().take(half).map(|c| c.to_lowercase());
+    /// True, if the bytes contain at least one byte beyond ASCII. Only
+    /// [`for_bytes`](PasswordInfo::for_bytes) detects these bytes, see
+    /// [`EXTENDED_BYTE_BASE`](EXTENDED_BYTE_BASE) for their contribution to
+    /// the base.
+    #[must_use]
+    pub const fn has_extended_byte(&self) -> bool {
+        self.has_group(GroupKind::ExtendedByte)
+    }
 
-    let backwards = password.iter().rev().take(half).map(|c| c.to_lowercase());
+    /// The amount of characters of the stripped password in each character
+    /// group.
+    #[must_use]
+    pub const fn group_composition(&self) -> GroupComposition {
+        self.composition
+    }
 
-    let is_palindrome = forwards.zip(backwards).all(|(f, b)| f.eq(b));
+    /// True, if the password contains at least one character of the given
+    /// group.
+    #[must_use]
+    pub const fn has_group(&self, kind: GroupKind) -> bool {
+        match kind {
+            GroupKind::Replace => self.has_replace,
+            GroupKind::Separator => self.has_separator,
+            GroupKind::OtherSpecial => self.has_other_special,
+            GroupKind::Lower => self.has_lower,
+            GroupKind::Upper => self.has_upper,
+            GroupKind::Digit => self.has_digit,
+            GroupKind::UnicodeLetter => self.has_unicode_letter,
+            GroupKind::Emoji => self.has_emoji,
+            GroupKind::Unclassified => self.has_unclassified,
+            GroupKind::ExtendedByte => self.has_extended_byte,
+        }
+    }
 
-    if is_palindrome {
-        password.truncate(half);
-    }
-}
-
-/// Common password sequences to remove
-static COMMON_SEQUENCES: &[&str] = &[
-    "asdf",
-    "jkl;",
-    ";lkj",
-    "fdsa",
-    "asdfghjkl",
-    "asdf ;lkj",
-    "0123456789",
-    "qwertyuiop",
-    "qwerty",
-    "zxcvbnm",
-    "abcdefghijklmnopqrstuvwxyz",
-    "password1",
-    "password!",
-    "password",
-    "Password",
-    "assword",
-    "picture1",
-    "Picture1",
-    "picture",
-    "Picture",
-    "asdf",
-    "rty567",
-    "senha",
-    "abc123",
-    "Million2",
-    "000000",
-    "1234",
-    "iloveyou",
-    "aaron431",
-    "qqww1122",
-    "123123",
-];
+    /// True, if the password contains all four classic character classes
+    /// that many legacy policies require: a lowercase letter, an uppercase
+    /// letter, a digit and a special character, which is a
+    /// [`replace`](PasswordInfo::has_replace_character),
+    /// [`separator`](PasswordInfo::has_separator_character) or
+    /// [`other special`](PasswordInfo::has_other_special_character)
+    /// character.
+    ///
+    /// Like all groups, the classes are checked on the stripped password.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfo;
+    ///
+    /// assert!(PasswordInfo::for_password("Tr0ub4dor&3").satisfies_complexity());
+    /// assert!(!PasswordInfo::for_password("Tr0ub4dor3").satisfies_complexity());
+    /// ```
+    #[must_use]
+    pub const fn satisfies_complexity(&self) -> bool {
+        self.satisfies_complexity_of(4)
+    }
 
-/// Removes common password sequences from the given password in place.
-fn remove_common_sequences(password: &mut Vec<char>) {
-    COMMON_SEQUENCES
-        .iter()
-        .map(|s| s.chars().collect::<Vec<char>>())
-        .for_each(|sequence| {
-            let len = sequence.len();
+    /// True, if the password contains at least the given amount of the four
+    /// classic character classes, see
+    /// [`satisfies_complexity`](PasswordInfo::satisfies_complexity).
+    ///
+    /// A policy like "at least three of upper, lower, digit and special"
+    /// becomes `info.satisfies_complexity_of(3)`.
+    #[must_use]
+    pub const fn satisfies_complexity_of(&self, min_classes: usize) -> bool {
+        self.complexity_class_count() >= min_classes
+    }
 
-            // Each sequence could occur multiple times.
-            // TODO: ignore case
-            while let Some(position) = password.windows(len).position(|w| w.eq(&sequence)) {
-                drop(password.drain(position..(position + len)))
-            }
-        });
-}
+    /// The amount of the four classic character classes the password
+    /// contains, see [`satisfies_complexity`](PasswordInfo::satisfies_complexity).
+    const fn complexity_class_count(&self) -> usize {
+        let special = self.has_replace || self.has_separator || self.has_other_special;
 
-/// Calculates `log_b(e^p)` where b is the base of the logarithm, e is the base
-/// of the exponent and p is the power.
-/// The calculation is done in logspace for each multiplication step to reduce
-/// memory usage ( `log_b(M * N) = log_b(M) + log_b(N)` ).
-fn log_power(exp_base: f64, power: usize, log_base: f64) -> f64 {
-    std::iter::repeat(exp_base.log(log_base))
-        .take(power as usize)
-        .sum()
-}
+        self.has_lower as usize
+            + self.has_upper as usize
+            + self.has_digit as usize
+            + special as usize
+    }
 
-#[cfg(test)]
-mod tests {
-    use crate::{
-        log_power, remove_common_sequences, remove_palindrome, remove_repeating_characters,
-        PasswordInfo, DIGIT_CHARS, LOWER_CHARS, OTHER_SPECIAL_CHARS, REPLACE_CHARS,
-        SEPARATOR_CHARS, UPPER_CHARS,
-    };
-    const ERROR_MARGIN: f64 = f64::EPSILON;
+    /// The bits of entropy this password gained compared to the previous
+    /// password, e.g. to tell the user how much stronger an edit made it.
+    ///
+    /// This is `self.get_entropy() - previous.get_entropy()`, so the gain is
+    /// positive if this password is stronger and negative if it is weaker.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfo;
+    ///
+    /// let before = PasswordInfo::for_password("Secret");
+    /// let after = PasswordInfo::for_password("Secret42!");
+    ///
+    /// let gain = after.entropy_gain_from(&before);
+    /// assert!(gain > 0.0);
+    /// assert_eq!(-gain, before.entropy_gain_from(&after));
+    /// ```
+    #[must_use]
+    pub fn entropy_gain_from(&self, previous: &Self) -> f64 {
+        self.get_entropy() - previous.get_entropy()
+    }
 
-    #[test]
-    fn test_entropy() {
-        // Password only uses lowercase => base = 26 with length of 7 characters
-        // https://www.wolframalpha.com/input/?i=log2%2826%5E7%29
-        let password = "letmein";
-        let expected = 7.0 * 26.0_f64.log10() / 2.0_f64.log10();
-        assert!((expected - PasswordInfo::for_password(password).get_entropy()) < ERROR_MARGIN);
+    /// True, if this password is stronger than the other password, e.g. to
+    /// check that a new password is an improvement over the old one.
+    ///
+    /// See [`compare_strength`](PasswordInfo::compare_strength) for the order.
+    #[must_use]
+    pub fn is_stronger_than(&self, other: &Self) -> bool {
+        self.compare_strength(other) == Ordering::Greater
+    }
 
-        // Password is empty => entropy = 0.0
-        let password = "";
-        let expected = 0.0;
-        assert!((expected - PasswordInfo::for_password(password).get_entropy()) < ERROR_MARGIN);
+    /// Compares the strength of this password with the other password.
+    ///
+    /// The passwords are ordered by their
+    /// [`entropy`](PasswordInfo::get_entropy). On a tie, the longer stripped
+    /// [`length`](PasswordInfo::length) wins and then the larger
+    /// [`base`](PasswordInfo::base), so a password of `base ^ length` equal
+    /// to another one is stronger, if it is longer.
+    #[must_use]
+    pub fn compare_strength(&self, other: &Self) -> Ordering {
+        self.get_entropy()
+            .total_cmp(&other.get_entropy())
+            .then(self.length.cmp(&other.length))
+            .then(self.base.cmp(&other.base))
+    }
 
-        // Password uses upper- and lowercase => base = 2*26 with length of 7 characters
-        // https://www.wolframalpha.com/input/?i=log2%28%282*26%29%5E7%29
-        let password = "LetMeIn";
-        let expected = 7.0 * (2.0 * 26.0_f64).log10() / 2.0_f64.log10();
-        assert!((expected - PasswordInfo::for_password(password).get_entropy()) < ERROR_MARGIN);
+    /// All groups the password contains at least one character of, in the
+    /// order of [`GroupKind::ALL`](GroupKind::ALL).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::{GroupKind, PasswordInfo};
+    ///
+    /// let groups = PasswordInfo::for_password("LetMeIn2").present_groups();
+    /// assert_eq!(vec![GroupKind::Lower, GroupKind::Upper, GroupKind::Digit], groups);
+    /// ```
+    #[must_use]
+    pub fn present_groups(&self) -> Vec<GroupKind> {
+        GroupKind::ALL
+            .iter()
+            .copied()
+            .filter(|&kind| self.has_group(kind))
+            .collect()
+    }
 
-        // Password contains one character for each group with length of 6
-        let password = "!_\"aA0";
-        let expected = 6.0
-            * ((REPLACE_CHARS.len()
-                + SEPARATOR_CHARS.len()
-                + OTHER_SPECIAL_CHARS.len()
-                + LOWER_CHARS.len()
-                + UPPER_CHARS.len()
-                + DIGIT_CHARS.len()) as f64)
-                .log10()
-            / 2.0_f64.log10();
-        assert!((expected - PasswordInfo::for_password(password).get_entropy()) < ERROR_MARGIN);
+    /// True, if the [`entropy`](PasswordInfo::get_entropy) of the password is
+    /// at least the given amount of bits.
+    #[must_use]
+    pub fn meets_entropy(&self, min_bits: f64) -> bool {
+        self.get_entropy() >= min_bits
     }
 
-    #[test]
-    fn test_log_power() {
-        // https://www.wolframalpha.com/input/?i=log2%2826%5E7%29
-        let expected = 7.0 * 26.0_f64.log10() / 2.0_f64.log10();
-        assert!((expected - log_power(26.0, 7, 2.0)).abs() < ERROR_MARGIN);
+    /// True, if the password meets all requirements of the given policy: the
+    /// minimum stripped length, the minimum entropy, all required character
+    /// groups and none of the forbidden sequences. This is the same as the
+    /// [`passed`](PolicyResult::passed) of [`evaluate`](PasswordInfo::evaluate).
+    ///
+    /// A password with a base below the
+    /// [`min_meaningful_base`](PasswordInfo::min_meaningful_base) is never
+    /// acceptable, as is a password that is
+    /// [`whitespace only`](PasswordInfo::is_whitespace_only) if the builder
+    /// rated it as [`weak`](PasswordInfoBuilder::weak_whitespace_only).
+    #[must_use]
+    pub fn is_acceptable(&self, policy: &Policy) -> bool {
+        if self.has_tiny_alphabet() || self.has_no_strength() {
+            return false;
+        }
+
+        self.length >= policy.min_length
+            && self.meets_entropy(policy.min_bits)
+            && policy
+                .required_groups
+                .iter()
+                .all(|&kind| self.has_group(kind))
+            && !policy
+                .forbidden_sequences
+                .iter()
+                .any(|sequence| self.lowercase_password.contains(sequence))
+    }
+
+    /// The groups required by the given policy the password does not contain
+    /// any character of, in the order of the policy, e.g. to show an inline
+    /// validation error for each of them.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::{GroupKind, PasswordInfo, Policy};
+    ///
+    /// let policy = Policy {
+    ///     required_groups: vec![GroupKind::Upper, GroupKind::Lower, GroupKind::Digit],
+    ///     ..Policy::default()
+    /// };
+    /// let missing = PasswordInfo::for_password("letmein").missing_required_groups(&policy);
+    /// # #[cfg(not(feature = "large-wordlist"))]
+    /// assert_eq!(vec![GroupKind::Upper, GroupKind::Digit], missing);
+    /// ```
+    #[must_use]
+    pub fn missing_required_groups(&self, policy: &Policy) -> Vec<GroupKind> {
+        let mut missing: Vec<GroupKind> = Vec::new();
+        for &kind in &policy.required_groups {
+            if !self.has_group(kind) && !missing.contains(&kind) {
+                missing.push(kind);
+            }
+        }
+        missing
+    }
+
+    /// Evaluates the password against each rule of the given policy.
+    ///
+    /// The forbidden sequences are searched in the original password, ignoring
+    /// the case. A base below the
+    /// [`min_meaningful_base`](PasswordInfo::min_meaningful_base) and a weak
+    /// whitespace only password are violations as well, like in
+    /// [`is_acceptable`](PasswordInfo::is_acceptable).
+    #[must_use]
+    pub fn evaluate(&self, policy: &Policy) -> PolicyResult {
+        let mut violations = Vec::new();
+
+        if self.length < policy.min_length {
+            violations.push(PolicyViolation::TooShort {
+                got: self.length,
+                required: policy.min_length,
+            });
+        }
+        if !self.meets_entropy(policy.min_bits) {
+            violations.push(PolicyViolation::EntropyTooLow {
+                got: self.get_entropy(),
+                required: policy.min_bits,
+            });
+        }
+        violations.extend(
+            self.missing_required_groups(policy)
+                .into_iter()
+                .map(PolicyViolation::MissingGroup),
+        );
+        violations.extend(
+            policy
+                .forbidden_sequences
+                .iter()
+                .filter(|sequence| self.lowercase_password.contains(sequence))
+                .cloned()
+                .map(PolicyViolation::ContainsSequence),
+        );
+        if self.has_tiny_alphabet() {
+            violations.push(PolicyViolation::TinyAlphabet {
+                got: self.base,
+                required: self.min_meaningful_base,
+            });
+        }
+        if self.has_no_strength() {
+            violations.push(PolicyViolation::WhitespaceOnly);
+        }
+
+        PolicyResult::new(violations)
+    }
+
+    /// The characters of all groups the password contains at least one
+    /// character of, which is the alphabet the [`base`](PasswordInfo::base)
+    /// is the size of.
+    ///
+    /// The characters are taken from the default groups like
+    /// [`LOWER_CHARS`](LOWER_CHARS), even if the info was calculated with a
+    /// custom [`CharsetConfig`](CharsetConfig). The unicode letter group has no
+    /// fixed characters and is therefore not part of the charset.
+    ///
+    /// This allocates a new string on each call and is meant for debugging.
+    #[must_use]
+    pub fn charset(&self) -> String {
+        let contains = [
+            self.has_replace,
+            self.has_separator,
+            self.has_other_special,
+            self.has_lower,
+            self.has_upper,
+            self.has_digit,
+        ];
+
+        GROUPS
+            .iter()
+            .zip(contains)
+            .filter(|(_, contains)| *contains)
+            .map(|((_, chars), _)| *chars)
+            .collect()
+    }
+
+    /// Human-readable tips to improve the password. There is one tip for each
+    /// character group the password does not contain, one tip if the stripped
+    /// length is below [`RECOMMENDED_LENGTH`](RECOMMENDED_LENGTH) or
+    /// [`too short`](PasswordInfo::is_too_short) and one tip if the password is
+    /// [`suspiciously long`](PasswordInfo::is_suspiciously_long).
+    #[must_use]
+    pub fn suggestions(&self) -> Vec<&'static str> {
+        let mut suggestions = Vec::new();
+
+        if self.is_suspiciously_long {
+            suggestions.push("Check the password, it is unusually long.");
+        }
+        if self.length < RECOMMENDED_LENGTH || self.is_too_short {
+            suggestions.push("Use a longer password.");
+        }
+        if !self.has_lower {
+            suggestions.push("Add a lowercase letter.");
+        }
+        if !self.has_upper {
+            suggestions.push("Add an uppercase letter.");
+        }
+        if !self.has_digit {
+            suggestions.push("Add a digit.");
+        }
+        if !self.has_replace {
+            suggestions.push("Add a replacement character like '!', '@' or '$'.");
+        }
+        if !self.has_separator {
+            suggestions.push("Add a separator character like '_', '-' or a space.");
+        }
+        if !self.has_other_special {
+            suggestions.push("Add a special character like '#', '%' or '?'.");
+        }
+
+        suggestions
+    }
+
+    /// Machine-readable weaknesses of the password, e.g. to show localized
+    /// tips instead of the English [`suggestions`](PasswordInfo::suggestions).
+    ///
+    /// There is one warning for each suggestion in the same order, followed by
+    /// one warning for each stripping pass that changed the password and one
+    /// for each matched common sequence.
+    #[must_use]
+    pub fn warnings(&self) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if self.is_suspiciously_long {
+            warnings.push(Warning::SuspiciouslyLong);
+        }
+        if self.length < RECOMMENDED_LENGTH || self.is_too_short {
+            warnings.push(Warning::TooShort);
+        }
+        let missing = [
+            (self.has_lower, Warning::MissingLowercase),
+            (self.has_upper, Warning::MissingUppercase),
+            (self.has_digit, Warning::MissingDigit),
+            (self.has_replace, Warning::MissingReplace),
+            (self.has_separator, Warning::MissingSeparator),
+            (self.has_other_special, Warning::MissingOtherSpecial),
+        ];
+        warnings.extend(
+            missing
+                .iter()
+                .filter(|(has, _)| !has)
+                .map(|(_, warning)| warning.clone()),
+        );
+        if self.has_tiny_alphabet() {
+            warnings.push(Warning::TinyAlphabet);
+        }
+        warnings.extend(
+            self.matched_sequences
+                .iter()
+                .cloned()
+                .map(Warning::ContainsCommonSequence),
+        );
+        if self.stripped_palindrome {
+            warnings.push(Warning::IsPalindrome);
+        }
+        if self.stripped_repeat {
+            warnings.push(Warning::RepeatedCharacters);
+        }
+
+        warnings
+    }
+
+    /// Summarizes all metrics of the password in a single
+    /// [`PasswordReport`](PasswordReport).
+    #[must_use]
+    pub fn report(&self) -> PasswordReport {
+        PasswordReport {
+            entropy: self.get_entropy(),
+            length: self.length,
+            base: self.base,
+            strength: self.strength(),
+            has_replace: self.has_replace,
+            has_separator: self.has_separator,
+            has_other_special: self.has_other_special,
+            has_lower: self.has_lower,
+            has_upper: self.has_upper,
+            has_digit: self.has_digit,
+            has_unicode_letter: self.has_unicode_letter,
+            has_emoji: self.has_emoji,
+            has_unclassified: self.has_unclassified,
+            has_extended_byte: self.has_extended_byte,
+            suggestions: self.suggestions(),
+        }
+    }
+
+    /// Calculates the entropy of the password based on: `log_2(base ^ length)`.
+    #[must_use]
+    pub fn get_entropy(&self) -> f64 {
+        self.get_entropy_base(2.0)
+    }
+
+    /// The [`entropy`](PasswordInfo::get_entropy) in bits, or `None` if a
+    /// non-empty password was stripped entirely.
+    ///
+    /// Both an empty password and a password that is entirely a known pattern
+    /// like `password` have `0.0` bits. This distinguishes them, e.g. to tell
+    /// the user that the password is entirely a known pattern instead of that
+    /// no password was entered.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfo;
+    ///
+    /// assert_eq!(Some(0.0), PasswordInfo::for_password("").entropy_opt());
+    /// assert_eq!(None, PasswordInfo::for_password("password").entropy_opt());
+    /// assert!(PasswordInfo::for_password("Tr0ub4dor&3").entropy_opt().is_some());
+    /// ```
+    #[must_use]
+    pub fn entropy_opt(&self) -> Option<f64> {
+        if self.length == 0 && self.original_length > 0 {
+            return None;
+        }
+
+        Some(self.get_entropy())
+    }
+
+    /// The [`entropy`](PasswordInfo::get_entropy) in bits clamped to the range
+    /// from `0.0` to the given maximum, e.g. to not display an absurd number
+    /// of bits for a pasted megabyte string.
+    ///
+    /// The clamping is only meant for display. The
+    /// [`strength`](PasswordInfo::strength) and all checks still use the full
+    /// entropy, and a password above the maximum reasonable length is
+    /// [`suspiciously long`](PasswordInfo::is_suspiciously_long) regardless of
+    /// the maximum. A negative maximum results in `0.0`, a maximum of `NaN` is
+    /// ignored.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfo;
+    ///
+    /// let info = PasswordInfo::for_password("Tr0ub4dor&3");
+    /// assert_eq!(info.get_entropy(), info.get_entropy_clamped(256.0));
+    /// assert_eq!(64.0, info.get_entropy_clamped(64.0));
+    /// ```
+    #[must_use]
+    pub fn get_entropy_clamped(&self, max: f64) -> f64 {
+        self.get_entropy().min(max).max(0.0)
+    }
+
+    /// How many more characters at the current [`base`](PasswordInfo::base)
+    /// are needed to reach the given entropy in bits:
+    /// `ceil((target_bits - entropy) / log_2(base))`, e.g. to advise the user
+    /// to add 3 more characters.
+    ///
+    /// Returns `0` if the [`entropy`](PasswordInfo::get_entropy) already
+    /// reaches the target. A base of `0` or `1` adds no entropy per character,
+    /// so the target can never be reached and `usize::MAX` is returned, as for
+    /// a target of `NaN` or infinity.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfo;
+    ///
+    /// let info = PasswordInfo::for_password("letmein");
+    /// # #[cfg(not(feature = "large-wordlist"))]
+    /// assert_eq!(6, info.chars_needed_for(60.0));
+    /// # #[cfg(not(feature = "large-wordlist"))]
+    /// assert_eq!(0, info.chars_needed_for(20.0));
+    /// ```
+    #[must_use]
+    pub fn chars_needed_for(&self, target_bits: f64) -> usize {
+        let missing = target_bits - self.get_entropy();
+        if missing <= 0.0 {
+            return 0;
+        }
+        if missing.is_nan() || self.base <= 1 {
+            return usize::MAX;
+        }
+
+        float::ceil(missing / float::log2(f64::from(self.base))) as usize
+    }
+
+    /// The [`entropy`](PasswordInfo::get_entropy) per character of the
+    /// stripped password, e.g. to compare passwords of different lengths.
+    ///
+    /// This is `log_2(base)`, plus the share of the bits of
+    /// [`Penalty::FixedBits`](Penalty::FixedBits). An empty stripped password
+    /// has `0.0` bits per character.
+    #[must_use]
+    pub fn entropy_per_char(&self) -> f64 {
+        if self.length == 0 {
+            return 0.0;
+        }
+
+        self.get_entropy() / self.length as f64
+    }
+
+    /// The entropy of the raw keyspace of the password in bits, as if nothing
+    /// was stripped: `log_2(original_base ^ original_length)`.
+    ///
+    /// The base is calculated from the groups of the password before
+    /// stripping, so a password that consists only of common sequences still
+    /// has a raw entropy. Control characters are not part of any group, so
+    /// they are not counted in the length either. The difference to
+    /// [`get_entropy`](PasswordInfo::get_entropy) is what the detected
+    /// patterns cost.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfo;
+    ///
+    /// let info = PasswordInfo::for_password("password1");
+    /// assert_eq!(0.0, info.get_entropy());
+    /// assert!(info.raw_entropy() > 45.0);
+    /// ```
+    #[must_use]
+    pub fn raw_entropy(&self) -> f64 {
+        log_power(f64::from(self.original_base), self.printable_length, 2.0)
+    }
+
+    /// The range the entropy of the password lies in, in bits, because the
+    /// stripping of patterns is heuristic.
+    ///
+    /// The lower bound is the [`entropy`](PasswordInfo::get_entropy) of the
+    /// stripped password. The upper bound is the entropy if every stripped
+    /// character was random after all: the same base with the
+    /// [`original length`](PasswordInfo::original_length) without the
+    /// stripped control characters instead of the stripped length. The true
+    /// value lies in between.
+    ///
+    /// The removed common sequences and dictionary words are part of the
+    /// original length, so the upper bound counts their characters as random
+    /// instead of adding their fixed bits, see
+    /// [`Penalty::FixedBits`](Penalty::FixedBits). The upper bound is never
+    /// below the lower bound.
+    ///
+    /// Both bounds use the base of the stripped password, so a password that
+    /// was stripped entirely without fixed bits has a range of `(0.0, 0.0)`.
+    /// See
+    /// [`raw_entropy`](PasswordInfo::raw_entropy) for the keyspace of the
+    /// password before stripping.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfo;
+    ///
+    /// let info = PasswordInfo::for_password("Tr0ub4dor&3aaaa");
+    /// let (lower, upper) = info.entropy_range();
+    /// assert_eq!(info.get_entropy(), lower);
+    /// assert!(upper > lower);
+    /// ```
+    #[must_use]
+    pub fn entropy_range(&self) -> (f64, f64) {
+        let lower = self.get_entropy();
+        let upper = entropy_for(self.base, self.printable_length) + self.pattern_bits;
+
+        (lower, upper.max(lower))
+    }
+
+    /// Calculates the entropy of the password in the given logarithm base
+    /// based on: `log_b(base ^ length)`. Use `2.0` for bits, `e` for nats and
+    /// `10.0` for dits.
+    ///
+    /// If the info was calculated with
+    /// [`Penalty::FixedBits`](Penalty::FixedBits), the entropy of the removed
+    /// common sequences is added, as is the entropy of the removed
+    /// [`dictionary words`](PasswordInfoBuilder::with_dictionary) and of the
+    /// [`pattern`](PasswordInfoBuilder::include_pattern_entropy).
+    ///
+    /// The logarithm base must be positive and not `1.0`, otherwise `NaN` is
+    /// returned.
+    #[must_use]
+    pub fn get_entropy_base(&self, log_base: f64) -> f64 {
+        if log_base <= 0.0 || log_base.is_nan() || (log_base - 1.0).abs() < f64::EPSILON {
+            return f64::NAN;
+        }
+
+        log_power(f64::from(self.base), self.length, log_base)
+            + (self.sequence_bits + self.pattern_bits) * float::log(2.0, log_base)
+    }
+
+    /// Calculates the entropy of the password in bits with the given
+    /// probability of each character, e.g. the real-world frequency of
+    /// letters, instead of assuming uniformly distributed characters.
+    ///
+    /// Each character of the stripped password contributes `-log_2(p(c))`.
+    /// Characters absent from the map, or with a probability outside of
+    /// `(0, 1]`, fall back to the uniform probability `1 / base`.
+    ///
+    /// If the info was calculated with
+    /// [`Penalty::FixedBits`](Penalty::FixedBits), the entropy of the removed
+    /// common sequences is added, as is the entropy of the removed
+    /// [`dictionary words`](PasswordInfoBuilder::with_dictionary) and of the
+    /// [`pattern`](PasswordInfoBuilder::include_pattern_entropy).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use pw_entropy::PasswordInfo;
+    ///
+    /// let info = PasswordInfo::for_password("easteregg");
+    /// let freqs: HashMap<char, f64> = [('e', 0.13), ('a', 0.08), ('s', 0.06)]
+    ///     .iter()
+    ///     .copied()
+    ///     .collect();
+    /// assert!(info.get_entropy_weighted(&freqs) < info.get_entropy());
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn get_entropy_weighted(&self, freqs: &HashMap<char, f64>) -> f64 {
+        let uniform = float::log2(f64::from(self.base));
+
+        self.frequencies
+            .iter()
+            .map(|(c, count)| {
+                let bits = match freqs.get(&c) {
+                    Some(&p) if p > 0.0 && p <= 1.0 => -float::log2(p),
+                    _ => uniform,
+                };
+                bits * count as f64
+            })
+            .sum::<f64>()
+            + self.sequence_bits
+            + self.pattern_bits
+    }
+
+    /// The theoretical maximum entropy for the stripped length of the
+    /// password, as if it contained a character of every group in
+    /// [`GROUPS`](GROUPS): `log_2(MAX_BASE ^ length)`, see
+    /// [`MAX_BASE`](MAX_BASE).
+    ///
+    /// Useful to show how close a password is to the ceiling for its length.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use pw_entropy::PasswordInfo;
+    ///
+    /// let info = PasswordInfo::for_password("ThisIsASecret");
+    /// let ratio = info.get_entropy() / info.max_possible_entropy();
+    /// assert!(ratio < 1.0);
+    /// ```
+    #[must_use]
+    pub fn max_possible_entropy(&self) -> f64 {
+        log_power(f64::from(MAX_BASE), self.length, 2.0)
+    }
+
+    /// Classifies the strength of the password based on its
+    /// [`entropy`](PasswordInfo::get_entropy). See
+    /// [`PasswordStrength`](PasswordStrength) for the thresholds.
+    ///
+    /// If the base is below the
+    /// [`min_meaningful_base`](PasswordInfo::min_meaningful_base) or the
+    /// password is [`too short`](PasswordInfo::is_too_short), the strength is
+    /// at most [`Weak`](PasswordStrength::Weak) regardless of the length. If
+    /// the password is
+    /// [`suspiciously long`](PasswordInfo::is_suspiciously_long), the strength
+    /// is at most [`Strong`](PasswordStrength::Strong). A password that is
+    /// [`whitespace only`](PasswordInfo::is_whitespace_only) is
+    /// [`VeryWeak`](PasswordStrength::VeryWeak), if the builder was configured
+    /// with [`weak_whitespace_only`](PasswordInfoBuilder::weak_whitespace_only).
+    #[must_use]
+    pub fn strength(&self) -> PasswordStrength {
+        self.strength_with(&ThresholdTable::default())
+    }
+
+    /// Classifies the strength of the password based on its
+    /// [`entropy`](PasswordInfo::get_entropy) with the thresholds of the
+    /// given table, but otherwise like [`strength`](PasswordInfo::strength).
+    #[must_use]
+    pub fn strength_with(&self, table: &ThresholdTable) -> PasswordStrength {
+        let strength = table.classify(self.get_entropy());
+
+        if self.has_no_strength() {
+            PasswordStrength::VeryWeak
+        } else if self.has_tiny_alphabet() || self.is_too_short {
+            strength.min(PasswordStrength::Weak)
+        } else if self.is_suspiciously_long {
+            strength.min(PasswordStrength::Strong)
+        } else {
+            strength
+        }
+    }
+
+    /// True, if the base is below the
+    /// [`min_meaningful_base`](PasswordInfo::min_meaningful_base).
+    const fn has_tiny_alphabet(&self) -> bool {
+        self.base < self.min_meaningful_base
+    }
+
+    /// True, if the password is whitespace only and rated as weak.
+    const fn has_no_strength(&self) -> bool {
+        self.weak_whitespace_only && self.is_whitespace_only
+    }
+
+    /// Estimates the average time an attacker needs to crack the password with
+    /// the given amount of guesses per second.
+    ///
+    /// On average an attacker needs to try half of all possible passwords,
+    /// which are `2 ^ entropy / 2` guesses. If the time exceeds the range of a
+    /// [`Duration`](Duration) or the given guess rate is not positive,
+    /// [`Duration::MAX`](Duration::MAX) will be returned.
+    ///
+    /// See [`ONLINE_THROTTLED_GUESS_RATE`](ONLINE_THROTTLED_GUESS_RATE),
+    /// [`ONLINE_UNTHROTTLED_GUESS_RATE`](ONLINE_UNTHROTTLED_GUESS_RATE),
+    /// [`OFFLINE_SLOW_HASH_GUESS_RATE`](OFFLINE_SLOW_HASH_GUESS_RATE) and
+    /// [`OFFLINE_GPU_GUESS_RATE`](OFFLINE_GPU_GUESS_RATE) for common attack
+    /// scenarios.
+    #[must_use]
+    pub fn crack_time(&self, guesses_per_second: f64) -> Duration {
+        if guesses_per_second <= 0.0 || guesses_per_second.is_nan() {
+            return Duration::MAX;
+        }
+
+        let guesses = float::exp2(self.get_entropy() - 1.0);
+        Duration::try_from_secs_f64(guesses / guesses_per_second).unwrap_or(Duration::MAX)
+    }
+
+    /// The approximate amount of guesses needed to try all possible
+    /// passwords, which is `2 ^ entropy` rounded to the nearest integer.
+    ///
+    /// An entropy of 128 bits or more exceeds the range of an `u128`, so
+    /// [`u128::MAX`](u128::MAX) will be returned instead.
+    #[must_use]
+    pub fn guesses(&self) -> u128 {
+        let entropy = self.get_entropy();
+        if entropy >= f64::from(u128::BITS) {
+            return u128::MAX;
+        }
+
+        // Rounds to the nearest integer to compensate the error of the
+        // logarithm. Float to integer casts saturate, which covers rounding up
+        // to 2^128.
+        (float::exp2(entropy) + 0.5) as u128
+    }
+
+    /// The entropy in bits each character of the original password
+    /// contributes, e.g. to color the characters in a user interface.
+    ///
+    /// Each character that was kept while stripping contributes
+    /// `log_2(base)` bits, each removed character contributes nothing. So the
+    /// sum is the [`entropy`](PasswordInfo::get_entropy) without the bits of
+    /// [`Penalty::FixedBits`](Penalty::FixedBits).
+    #[must_use]
+    pub fn per_char_entropy(&self) -> Vec<f64> {
+        let bits = log_power(f64::from(self.base), 1, 2.0);
+
+        self.kept
+            .iter()
+            .map(|&kept| if kept { bits } else { 0.0 })
+            .collect()
+    }
+
+    /// Calculates the Shannon entropy of the password based on:
+    /// `-sum(p_i * log_2(p_i))`, where `p_i` is the relative frequency of each
+    /// distinct character of the stripped password.
+    ///
+    /// Unlike [`get_entropy`](PasswordInfo::get_entropy) this takes the actual
+    /// character distribution into account. The result is measured in bits per
+    /// character, multiply it with the [`length`](PasswordInfo::length) to get
+    /// the entropy of the whole password.
+    #[must_use]
+    pub fn get_shannon_entropy(&self) -> f64 {
+        let total = self.length as f64;
+
+        self.frequencies
+            .counts()
+            .map(|count| {
+                let probability = count as f64 / total;
+                -probability * float::log2(probability)
+            })
+            .sum()
+    }
+}
+
+/// A one-line summary like `entropy=53.2 bits, length=9, base=62,
+/// strength=Strong`. The format is stable, so it can be parsed. The entropy
+/// is rounded to one decimal.
+impl fmt::Display for PasswordInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "entropy={:.1} bits, length={}, base={}, strength={}",
+            self.get_entropy(),
+            self.length,
+            self.base,
+            self.strength()
+        )
+    }
+}
+
+/// Calculates the info of the given password, see
+/// [`PasswordInfo::for_password`](PasswordInfo::for_password).
+impl From<&str> for PasswordInfo {
+    fn from(password: &str) -> Self {
+        Self::for_password(password)
+    }
+}
+
+/// Calculates the info of the given password, see
+/// [`PasswordInfo::for_password`](PasswordInfo::for_password). This never
+/// fails.
+///
+/// The string is the password itself, not the [`Display`](fmt::Display)
+/// output of an info.
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::PasswordInfo;
+///
+/// let info: PasswordInfo = "letmein".parse().unwrap();
+/// # #[cfg(not(feature = "large-wordlist"))]
+/// assert_eq!(7, info.length());
+/// ```
+impl FromStr for PasswordInfo {
+    type Err = Infallible;
+
+    fn from_str(password: &str) -> Result<Self, Self::Err> {
+        Ok(Self::for_password(password))
+    }
+}
+
+/// Calculates the entropy of the given password in bits.
+///
+/// This is a shortcut for
+/// `PasswordInfo::for_password(password).get_entropy()`, see
+/// [`PasswordInfo::for_password`](PasswordInfo::for_password).
+///
+/// ## Example
+/// ```rust
+/// let entropy = pw_entropy::entropy_bits("ThisIsASecret");
+/// assert!(entropy > 60.0);
+/// ```
+#[must_use]
+pub fn entropy_bits(password: &str) -> f64 {
+    PasswordInfo::for_password(password).get_entropy()
+}
+
+/// Calculates the entropy in bits of a password of the given base and
+/// length: `log_2(base ^ length)`, e.g. for a password of a known generator.
+///
+/// A length of `0` always results in `0.0` bits. A base of `0` also results
+/// in `0.0` bits instead of negative infinity, like a base of `1`, because
+/// there is nothing to guess.
+///
+/// ## Example
+/// ```rust
+/// // 16 random lowercase letters
+/// let entropy = pw_entropy::entropy_for(26, 16);
+/// assert!((entropy - 75.207_6).abs() < 0.001);
+/// ```
+#[must_use]
+pub fn entropy_for(base: u16, length: usize) -> f64 {
+    if base == 0 {
+        return 0.0;
+    }
+
+    log_power(f64::from(base), length, 2.0)
+}
+
+/// Compares the strength of the two given passwords.
+///
+/// This is a shortcut for [`PasswordInfo::compare_strength`], which
+/// documents the order.
+///
+/// ## Example
+/// ```rust
+/// use core::cmp::Ordering;
+///
+/// assert_eq!(Ordering::Less, pw_entropy::compare("letmein", "Tr0ub4dor&3"));
+/// ```
+#[must_use]
+pub fn compare(a: &str, b: &str) -> Ordering {
+    PasswordInfo::for_password(a).compare_strength(&PasswordInfo::for_password(b))
+}
+
+/// The transformations that changed the password while stripping it.
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(clippy::struct_excessive_bools)]
+struct Stripped {
+    /// A palindrome was removed.
+    palindrome: bool,
+    /// At least one common sequence was removed.
+    sequence: bool,
+    /// At least one repeating character or pattern was removed.
+    repeat: bool,
+    /// At least one control character was removed.
+    control: bool,
+    /// The entropy in bits of the removed common sequences and dictionary
+    /// words.
+    sequence_bits: f64,
+}
+
+/// The character groups a password contains at least one character of.
+#[derive(Clone, Debug, Default)]
+#[allow(clippy::struct_excessive_bools)]
+struct Groups {
+    /// The password contains at least one replace character.
+    replace: bool,
+    /// The password contains at least one separator character.
+    separator: bool,
+    /// The password contains at least one other special character.
+    other_special: bool,
+    /// The password contains at least one lower character.
+    lower: bool,
+    /// The password contains at least one upper character.
+    upper: bool,
+    /// The password contains at least one digit.
+    digit: bool,
+    /// The password contains at least one letter beyond ASCII.
+    unicode_letter: bool,
+    /// The password contains at least one emoji.
+    emoji: bool,
+    /// The password contains at least one printable ASCII character that is
+    /// not part of any group.
+    unclassified: bool,
+    /// The bytes contain at least one byte beyond ASCII.
+    extended_byte: bool,
+    /// The amount of characters in each group.
+    composition: GroupComposition,
+    /// The amount of whitespace separated words.
+    word_count: usize,
+    /// The last added character is part of a word.
+    in_word: bool,
+    /// The distinct scripts of all letters in the order of their appearance.
+    #[cfg(feature = "unicode-script")]
+    scripts: Vec<Script>,
+    /// The distinct scripts of the letters beyond ASCII.
+    #[cfg(feature = "unicode-script")]
+    unicode_scripts: Vec<Script>,
+    /// Each script of the letters beyond ASCII contributes its own base.
+    #[cfg(feature = "unicode-script")]
+    script_aware: bool,
+}
+
+impl Groups {
+    /// Creates empty groups, whose base is calculated like configured by the
+    /// given builder.
+    #[cfg_attr(
+        not(feature = "unicode-script"),
+        allow(unused_variables, clippy::unnecessary_struct_initialization)
+    )]
+    fn for_builder(builder: &PasswordInfoBuilder<'_>) -> Self {
+        Self {
+            #[cfg(feature = "unicode-script")]
+            script_aware: builder.script_aware_base,
+            ..Self::default()
+        }
+    }
+
+    /// Adds the groups the given character is part of.
+    fn add(&mut self, c: char, charset: &CharsetConfig<'_>) {
+        self.replace |= charset.replace.contains(c);
+        self.separator |= charset.separator.contains(c);
+        self.other_special |= charset.other_special.contains(c);
+        self.lower |= charset.lower.contains(c);
+        self.upper |= charset.upper.contains(c);
+        self.digit |= charset.digit.contains(c);
+        let unicode_letter = !c.is_ascii() && c.is_alphabetic() && !charset.contains(c);
+        self.unicode_letter |= unicode_letter;
+        self.emoji |= is_emoji(c) && !charset.contains(c);
+        self.unclassified |= is_printable_ascii(c) && !charset.contains(c);
+
+        #[cfg(feature = "unicode-script")]
+        if let Some(script) = script::script_of(c) {
+            if !self.scripts.contains(&script) {
+                self.scripts.push(script);
+            }
+            if unicode_letter && !self.unicode_scripts.contains(&script) {
+                self.unicode_scripts.push(script);
+            }
+        }
+
+        if c.is_whitespace() {
+            self.in_word = false;
+        } else if !self.in_word {
+            self.in_word = true;
+            self.word_count += 1;
+        }
+
+        let composition = &mut self.composition;
+        let count = if charset.replace.contains(c) {
+            &mut composition.replace
+        } else if charset.separator.contains(c) {
+            &mut composition.separator
+        } else if charset.other_special.contains(c) {
+            &mut composition.other_special
+        } else if charset.lower.contains(c) {
+            &mut composition.lower
+        } else if charset.upper.contains(c) {
+            &mut composition.upper
+        } else if charset.digit.contains(c) {
+            &mut composition.digit
+        } else {
+            &mut composition.other
+        };
+        *count += 1;
+    }
+
+    /// Adds a byte beyond ASCII, which is only part of the extended group.
+    const fn add_extended_byte(&mut self) {
+        self.extended_byte = true;
+        self.composition.other += 1;
+
+        if !self.in_word {
+            self.in_word = true;
+            self.word_count += 1;
+        }
+    }
+
+    /// Calculates the base, which combines the sizes of all groups with the
+    /// given model.
+    fn base(&self, charset: &CharsetConfig<'_>, model: BaseModel) -> u16 {
+        let contains = [
+            self.replace,
+            self.separator,
+            self.other_special,
+            self.lower,
+            self.upper,
+            self.digit,
+        ];
+
+        let unicode_letter = self
+            .unicode_letter
+            .then(|| usize::from(charset.unicode_letter_base));
+        #[cfg(feature = "unicode-script")]
+        let unicode_letter: Vec<usize> = if self.script_aware {
+            self.unicode_scripts
+                .iter()
+                .map(|&script| script::script_base(script, charset))
+                .collect()
+        } else {
+            unicode_letter.into_iter().collect()
+        };
+
+        let groups = charset.groups();
+        let sizes = groups
+            .iter()
+            .zip(contains)
+            .filter(|(_, contains)| *contains)
+            .map(|(chars, _)| chars.chars().count())
+            .chain(unicode_letter)
+            .chain(self.emoji.then(|| usize::from(EMOJI_BASE)))
+            .chain(self.unclassified.then(|| charset.unclassified_count()))
+            .chain(self.extended_byte.then(|| usize::from(EXTENDED_BYTE_BASE)));
+
+        model.combine(sizes) as u16
+    }
+}
+
+/// The frequencies of the characters of a stripped password, sorted by the
+/// character.
+///
+/// The [`Debug`](core::fmt::Debug) implementation only shows the amount of
+/// distinct characters to not leak the characters of the password.
+#[derive(Clone, Default)]
+struct CharFrequencies(Vec<(char, usize)>);
+
+impl CharFrequencies {
+    /// Counts the frequencies of the given characters.
+    fn from_chars(password: &[char]) -> Self {
+        let mut frequencies = Self::default();
+        for &c in password {
+            frequencies.add(c);
+        }
+        frequencies
+    }
+
+    /// Increments the frequency of the given character.
+    fn add(&mut self, c: char) {
+        match self.0.binary_search_by_key(&c, |&(k, _)| k) {
+            Ok(index) => self.0[index].1 += 1,
+            Err(index) => self.0.insert(index, (c, 1)),
+        }
+    }
+
+    /// The amount of distinct characters.
+    fn distinct(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The frequency of each distinct character.
+    fn counts(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter().map(|&(_, count)| count)
+    }
+
+    /// Each distinct character with its frequency.
+    #[cfg(feature = "std")]
+    fn iter(&self) -> impl Iterator<Item = (char, usize)> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for CharFrequencies {
+    fn zeroize(&mut self) {
+        for (c, count) in &mut self.0 {
+            c.zeroize();
+            count.zeroize();
+        }
+        self.0.clear();
+    }
+}
+
+impl fmt::Debug for CharFrequencies {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CharFrequencies")
+            .field("distinct", &self.0.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Sums the sizes of all character groups in [`GROUPS`](GROUPS). The groups
+/// only contain ASCII characters, so the size of a group is its length in
+/// bytes.
+#[allow(clippy::cast_possible_truncation)]
+const fn max_base() -> u16 {
+    let mut base = 0;
+    let mut i = 0;
+    while i < GROUPS.len() {
+        base += GROUPS[i].1.len();
+        i += 1;
+    }
+    base as u16
+}
+
+/// The symbol of the given character in a hashcat-style mask, see
+/// [`PasswordInfo::mask`](PasswordInfo::mask).
+const fn mask_symbol(c: char) -> &'static str {
+    match c {
+        'a'..='z' => "?l",
+        'A'..='Z' => "?u",
+        '0'..='9' => "?d",
+        c if is_printable_ascii(c) => "?s",
+        _ => "?b",
+    }
+}
+
+/// True, if the given character is an emoji, see [`EMOJI_BASE`](EMOJI_BASE).
+const fn is_emoji(c: char) -> bool {
+    matches!(
+        c,
+        '\u{2600}'..='\u{27BF}'
+            | '\u{1F300}'..='\u{1F64F}'
+            | '\u{1F680}'..='\u{1F6FF}'
+            | '\u{1F900}'..='\u{1F9FF}'
+            | '\u{1FA70}'..='\u{1FAFF}'
+    )
+}
+
+/// True, if the given character is printable ASCII, that is in the range from
+/// the space `' '` to the tilde `'~'`.
+const fn is_printable_ascii(c: char) -> bool {
+    matches!(c, ' '..='~')
+}
+
+/// Marks each character of the original password of the given length that
+/// was kept while stripping, given the original positions of the kept
+/// characters.
+fn kept_characters(original_length: usize, positions: &[usize]) -> Vec<bool> {
+    let mut kept = vec![false; original_length];
+    for &position in positions {
+        kept[position] = true;
+    }
+
+    kept
+}
+
+/// Removes repeating characters from the password in place.
+///
+/// Each run of repeating characters is truncated to `max_repeat` characters,
+/// so with `1` the run `aaa` becomes `a` and with `2` it becomes `aa`. A
+/// `max_repeat` of `0` is treated like `1`.
+///
+/// If `case_insensitive` is true, characters that are equal when lowercased
+/// are also repeating, like `aAaA`. The full lowercase mapping is compared,
+/// because some characters like `İ` lowercase to multiple characters. The
+/// first characters of each repetition are kept.
+///
+/// If `positions` are given, the entries of each removed character are
+/// removed from them, too, so they still belong to the same characters.
+fn remove_repeating_characters(
+    password: &mut Vec<char>,
+    mut positions: Option<&mut Vec<usize>>,
+    case_insensitive: bool,
+    max_repeat: usize,
+) {
+    let max_repeat = max_repeat.max(1);
+    let repeats =
+        |a: char, b: char| a == b || (case_insensitive && a.to_lowercase().eq(b.to_lowercase()));
+
+    let mut run = 0;
+    let mut length = 0;
+    for index in 0..password.len() {
+        let c = password[index];
+        run = if index > 0 && repeats(password[index - 1], c) {
+            run + 1
+        } else {
+            1
+        };
+
+        // `length <= index`, so `password[index - 1]` is never overwritten
+        // by another character before it is compared.
+        if run <= max_repeat {
+            password[length] = c;
+            if let Some(positions) = positions.as_deref_mut() {
+                positions[length] = positions[index];
+            }
+            length += 1;
+        }
+    }
+    password.truncate(length);
+    if let Some(positions) = positions {
+        positions.truncate(length);
+    }
+}
+
+/// The longest period of a repeating unit that is removed anywhere in the
+/// password, see [`remove_short_periods`].
+const MAX_SHORT_PERIOD: usize = 3;
+
+/// The minimum amount of repetitions of a short unit to be removed, see
+/// [`remove_short_periods`].
+const MIN_SHORT_PERIOD_REPETITIONS: usize = 3;
+
+/// Removes runs of short repeating units like `a1a1a1` or `xyzxyzxyz` anywhere
+/// in the password in place.
+///
+/// Each run of at least [`MIN_SHORT_PERIOD_REPETITIONS`] repetitions of a unit
+/// of two to [`MAX_SHORT_PERIOD`] characters is reduced to a single unit, so
+/// `Xa1a1a1a1Y` becomes `Xa1Y`. The shortest period wins. Repeating single
+/// characters are left to [`remove_repeating_characters`].
+///
+/// If `positions` are given, the entries of each removed character are
+/// removed from them, too.
+fn remove_short_periods(password: &mut Vec<char>, mut positions: Option<&mut Vec<usize>>) {
+    let len = password.len();
+    let mut length = 0;
+    let mut index = 0;
+
+    while index < len {
+        let run = (2..=MAX_SHORT_PERIOD)
+            .filter(|&period| index + period <= len)
+            .filter(|&period| {
+                // A unit of a single repeating character has period 1
+                password[index..index + period]
+                    .windows(2)
+                    .any(|pair| pair[0] != pair[1])
+            })
+            .map(|period| {
+                let end = (index + period..len)
+                    .find(|&end| password[end] != password[end - period])
+                    .unwrap_or(len);
+                (period, (end - index) / period)
+            })
+            .find(|&(_, repetitions)| repetitions >= MIN_SHORT_PERIOD_REPETITIONS);
+
+        // `length <= index`, so the unit is never overwritten before it is
+        // copied.
+        let (unit, skip) = run.map_or((1, 1), |(period, repetitions)| {
+            (period, period * repetitions)
+        });
+        password.copy_within(index..index + unit, length);
+        if let Some(positions) = positions.as_deref_mut() {
+            positions.copy_within(index..index + unit, length);
+        }
+        length += unit;
+        index += skip;
+    }
+    password.truncate(length);
+    if let Some(positions) = positions {
+        positions.truncate(length);
+    }
+}
+
+/// Removes a repeating pattern like `abcabcabc` if it dominates the password.
+///
+/// If a prefix of at least half of the password consists of at least two
+/// repetitions of the same pattern, the prefix will be reduced to a single
+/// pattern in place. The periods of all prefixes are calculated in linear time
+/// with the prefix function of the Knuth-Morris-Pratt algorithm.
+///
+/// Repeating single characters are left to [`remove_repeating_characters`],
+/// so a run like `aa` that was kept by a `max_repeat` above `1` is not
+/// shortened again.
+///
+/// If `positions` are given, the entries of each removed character are
+/// removed from them, too.
+fn remove_repeating_patterns(password: &mut Vec<char>, positions: Option<&mut Vec<usize>>) {
+    let len = password.len();
+
+    // prefix[i] is the length of the longest proper prefix of password[..=i]
+    // that is also a suffix of it.
+    let mut prefix = vec![0; len];
+    for i in 1..len {
+        let mut k = prefix[i - 1];
+        while k > 0 && password[i] != password[k] {
+            k = prefix[k - 1];
+        }
+        if password[i] == password[k] {
+            k += 1;
+        }
+        prefix[i] = k;
+    }
+
+    let repeated = (1..=len)
+        .rev()
+        .take_while(|&prefix_len| 2 * prefix_len >= len)
+        .map(|prefix_len| (prefix_len, prefix_len - prefix[prefix_len - 1]))
+        .find(|&(prefix_len, period)| period > 1 && prefix_len >= 2 * period);
+
+    if let Some((prefix_len, period)) = repeated {
+        drop(password.drain(period..prefix_len));
+        if let Some(positions) = positions {
+            drop(positions.drain(period..prefix_len));
+        }
+    }
+}
+
+/// Removes the palindrome if it exists. If the password is a palindrome the
+/// half of the palindrome will be removed in place.
+///
+/// Only the unique information of the palindrome is kept: the first half and,
+/// for an odd length, the middle character exactly once. So `abba` becomes `ab`
+/// and `abcba` becomes `abc`.
+///
+/// Up to `tolerance` mirrored pairs may differ, so with a tolerance of `1` the
+/// near-palindrome `abccbx` becomes `abc`. At least one pair must match, so a
+/// large tolerance does not turn every password into a palindrome.
+///
+/// A password of a single repeating character like `aaaa` is a run, not a
+/// palindrome, and is left to [`remove_repeating_characters`], which respects
+/// its `max_repeat`.
+///
+/// If `positions` are given, the entries of each removed character are
+/// removed from them, too.
+fn remove_palindrome(
+    password: &mut Vec<char>,
+    positions: Option<&mut Vec<usize>>,
+    tolerance: usize,
+) {
+    if password.windows(2).all(|pair| pair[0] == pair[1]) {
+        return;
+    }
+
+    // The middle character of an odd length has no mirrored counterpart and
+    // therefore is not compared.
+    let pairs = password.len() / 2;
+
+    let forwards = password.iter().take(pairs).map(|c| c.to_lowercase());
+
+    let backwards = password.iter().rev().take(pairs).map(|c| c.to_lowercase());
+
+    let mismatches = forwards
+        .zip(backwards)
+        .filter(|(f, b)| !f.clone().eq(b.clone()))
+        .count();
+    let is_palindrome = mismatches <= tolerance && (mismatches == 0 || mismatches < pairs);
+
+    if is_palindrome {
+        password.truncate(password.len() - pairs);
+        if let Some(positions) = positions {
+            positions.truncate(password.len());
+        }
+    }
+}
+
+/// The built-in list of common password sequences, one per line, see
+/// [`parse_sequences`](parse_sequences).
+static COMMON_SEQUENCES_FILE: &str = include_str!("../data/common.txt");
+
+/// The additional common passwords of the feature `large-wordlist`.
+#[cfg(feature = "large-wordlist")]
+static LARGE_SEQUENCES_FILE: &str = include_str!("../data/common-large.txt");
+
+/// Parses the built-in lists of common sequences.
+fn parse_common_sequences() -> Vec<&'static str> {
+    #[cfg_attr(not(feature = "large-wordlist"), allow(unused_mut))]
+    let mut sequences = parse_sequences(COMMON_SEQUENCES_FILE);
+    #[cfg(feature = "large-wordlist")]
+    sequences.extend(parse_sequences(LARGE_SEQUENCES_FILE));
+
+    sequences
+}
+
+/// Parses a list of sequences with one sequence per line. Blank lines and
+/// lines starting with `#` are skipped. All other whitespace is part of the
+/// sequence, like in `asdf ;lkj`.
+fn parse_sequences(file: &str) -> Vec<&str> {
+    file.lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .collect()
+}
+
+/// The built-in common sequences, which are parsed once at first use and
+/// shared between all threads.
+#[cfg(feature = "std")]
+fn common_sequence_list() -> &'static [&'static str] {
+    /// The lazily parsed sequences.
+    static SEQUENCES: OnceLock<Vec<&'static str>> = OnceLock::new();
+
+    SEQUENCES.get_or_init(parse_common_sequences)
+}
+
+/// The built-in common sequences, see
+/// [`common_sequence_list`](common_sequence_list).
+#[cfg(feature = "std")]
+fn common_sequences<'a>() -> Cow<'a, [&'a str]> {
+    Cow::Borrowed(common_sequence_list())
+}
+
+/// The built-in common sequences. Without the feature `std` they cannot be
+/// shared, so they are parsed on each call.
+#[cfg(not(feature = "std"))]
+fn common_sequences<'a>() -> Cow<'a, [&'a str]> {
+    Cow::Owned(parse_common_sequences())
+}
+
+/// The prepared [`common sequences`](common_sequences), which are only
+/// prepared once and shared between all threads.
+#[cfg(feature = "std")]
+fn common_sequence_chars() -> &'static SequenceSet {
+    /// The lazily prepared sequences.
+    static SEQUENCES: OnceLock<SequenceSet> = OnceLock::new();
+
+    SEQUENCES.get_or_init(|| SequenceSet::new(&common_sequences()))
+}
+
+/// Removes the palindrome and the predictable case the builder detects from the
+/// given password and the entries of the removed characters from `positions`
+/// in place, records the removal of the palindrome in `stripped` and the
+/// password after each stage in the trace.
+fn strip_palindrome_and_case(
+    password: &mut Vec<char>,
+    positions: &mut Vec<usize>,
+    builder: &PasswordInfoBuilder<'_>,
+    stripped: &mut Stripped,
+    trace: &mut Option<AnalysisTrace>,
+) {
+    if builder.strip_palindrome {
+        let length = password.len();
+        remove_palindrome(password, Some(positions), builder.palindrome_tolerance);
+        stripped.palindrome = password.len() != length;
+        trace::record(trace, TraceStage::Palindrome, password);
+    }
+
+    if builder.dampen_leading_capital && case::remove_leading_capital(password, Some(positions)) {
+        trace::record(trace, TraceStage::LeadingCapital, password);
+    }
+
+    if builder.detect_case_pattern {
+        let _ = case::remove_case_pattern(password, Some(positions));
+        trace::record(trace, TraceStage::CasePattern, password);
+    }
+}
+
+/// Removes the common sequences of the builder from the given password and the
+/// entries of the removed characters from `positions` in place, records the
+/// removal in `stripped` and the password in the trace.
+///
+/// Returns the distinct removed sequences in the order they were removed.
+fn strip_sequences(
+    password: &mut Vec<char>,
+    positions: &mut Vec<usize>,
+    builder: &PasswordInfoBuilder<'_>,
+    sequences: &SequenceSet,
+    stripped: &mut Stripped,
+    trace: &mut Option<AnalysisTrace>,
+) -> Vec<String> {
+    if !builder.strip_sequences {
+        return Vec::new();
+    }
+
+    let mut removed =
+        remove_common_sequences(password, Some(positions), sequences, builder.detect_leet);
+    if builder.ignore_separators_in_sequences {
+        removed.extend(remove_separated_sequences(
+            password,
+            Some(positions),
+            builder.charset.separator,
+            sequences,
+            builder.detect_leet,
+        ));
+    }
+
+    stripped.sequence = !removed.is_empty();
+    if builder.sequence_penalty == Penalty::FixedBits && !removed.is_empty() {
+        stripped.sequence_bits =
+            removed.len() as f64 * float::log2(sequences.distinct_count() as f64);
+    }
+
+    let mut matched_sequences: Vec<String> = Vec::new();
+    for index in removed {
+        let sequence = builder.sequences[index];
+        if !matched_sequences.iter().any(|matched| matched == sequence) {
+            matched_sequences.push(String::from(sequence));
+        }
+    }
+
+    trace::record(trace, TraceStage::Sequences, password);
+    matched_sequences
+}
+
+/// Removes the dictionary words of the builder from the given password and the
+/// entries of the removed characters from `positions` in place, records the
+/// removal in `stripped`, adds the removed words to `matched_sequences` and
+/// records the password in the trace.
+fn strip_dictionary_words(
+    password: &mut Vec<char>,
+    positions: &mut Vec<usize>,
+    builder: &PasswordInfoBuilder<'_>,
+    stripped: &mut Stripped,
+    matched_sequences: &mut Vec<String>,
+    trace: &mut Option<AnalysisTrace>,
+) {
+    if builder.dictionary.is_empty() {
+        return;
+    }
+
+    let removed = builder.dictionary.remove_words(password, Some(positions));
+    stripped.sequence |= !removed.is_empty();
+    stripped.sequence_bits += removed.len() as f64 * float::log2(builder.dictionary.len() as f64);
+
+    for word in removed {
+        if !matched_sequences.contains(&word) {
+            matched_sequences.push(word);
+        }
+    }
+    trace::record(trace, TraceStage::Dictionary, password);
+}
+
+/// Removes the keyboard walks, dates and repeats the builder detects from the
+/// given password and the entries of the removed characters from `positions`
+/// in place, records the removal of repeats in `stripped` and the password
+/// after each stage in the trace.
+fn strip_patterns(
+    password: &mut Vec<char>,
+    positions: &mut Vec<usize>,
+    builder: &PasswordInfoBuilder<'_>,
+    stripped: &mut Stripped,
+    trace: &mut Option<AnalysisTrace>,
+) {
+    if let Some(layout) = &builder.keyboard_layout {
+        layout.remove_walks(password, Some(positions));
+        trace::record(trace, TraceStage::KeyboardWalks, password);
+    }
+    if builder.detect_dates {
+        date::remove_dates(password, Some(positions));
+        trace::record(trace, TraceStage::Dates, password);
+    }
+
+    if builder.strip_repeats {
+        let length = password.len();
+        remove_repeating_characters(
+            password,
+            Some(positions),
+            builder.case_insensitive_dedup,
+            builder.max_repeat,
+        );
+        remove_short_periods(password, Some(positions));
+        remove_repeating_patterns(password, Some(positions));
+        stripped.repeat = password.len() != length;
+        trace::record(trace, TraceStage::Repeats, password);
+    }
+}
+
+/// Removes the given common password sequences from the given password in place.
+///
+/// Removing a sequence can join the surrounding characters to a new sequence,
+/// like `qwe1234rty` becomes `qwerty`. Therefore the removal is repeated until
+/// a full pass over all sequences removes nothing. This always terminates,
+/// because every repetition shrinks the password.
+///
+/// Each sequence is also matched in reverse, like `fdsa` for `asdf`. Empty
+/// sequences are skipped, because they would match everywhere without removing
+/// anything.
+///
+/// If `detect_leet` is true, common leet substitutions like `p@ssw0rd` will also
+/// be matched. See [`leet_alternatives`](leet_alternatives).
+///
+/// Returns the index of the sequence for each removal, so a sequence that was
+/// removed multiple times is contained multiple times.
+///
+/// Sequences that do not occur in the current password are skipped, if the
+/// [`candidates`](SequenceSet::candidates) are known.
+///
+/// If `positions` are given, the entries of each removed character are
+/// removed from them, too, see [`remove_tracked_sequences`](remove_tracked_sequences).
+fn remove_common_sequences(
+    password: &mut Vec<char>,
+    positions: Option<&mut Vec<usize>>,
+    sequences: &SequenceSet,
+    detect_leet: bool,
+) -> Vec<usize> {
+    remove_tracked_sequences(password, positions, sequences, detect_leet)
+}
+
+/// Removes the given common password sequences from the given password in
+/// place, while ignoring the given separator characters.
+///
+/// The sequences are searched in a shadow copy of the password without the
+/// separators, so `p-a-s-s-w-o-r-d` still contains `password`. The matched
+/// characters are then removed from the password, but the separators are
+/// kept.
+///
+/// Returns the index of the sequence for each removal, like
+/// [`remove_common_sequences`](remove_common_sequences). If `positions` are
+/// given, the entries of each removed character are removed from them, too.
+fn remove_separated_sequences(
+    password: &mut Vec<char>,
+    positions: Option<&mut Vec<usize>>,
+    separators: &str,
+    sequences: &SequenceSet,
+    detect_leet: bool,
+) -> Vec<usize> {
+    // The index in the password of each character of the shadow copy.
+    let (mut shadow, mut indices): (Vec<char>, Vec<usize>) = password
+        .iter()
+        .enumerate()
+        .filter(|(_, &c)| !separators.contains(c))
+        .map(|(index, &c)| (c, index))
+        .unzip();
+
+    let removed = remove_tracked_sequences(&mut shadow, Some(&mut indices), sequences, detect_leet);
+
+    if !removed.is_empty() {
+        let mut keep: Vec<bool> = password.iter().map(|&c| separators.contains(c)).collect();
+        for index in indices {
+            keep[index] = true;
+        }
+
+        if let Some(positions) = positions {
+            let mut keep = keep.iter();
+            positions.retain(|_| keep.next().copied().unwrap_or(true));
+        }
+        let mut keep = keep.into_iter();
+        password.retain(|_| keep.next().unwrap_or(true));
+    }
+
+    #[cfg(feature = "zeroize")]
+    shadow.zeroize();
+
+    removed
+}
+
+/// Removes the given common password sequences from the given password in
+/// place, see [`remove_common_sequences`](remove_common_sequences).
+///
+/// If `positions` are given, the entries of each removed character are
+/// removed from them, too, so they still belong to the same characters.
+fn remove_tracked_sequences(
+    password: &mut Vec<char>,
+    mut positions: Option<&mut Vec<usize>>,
+    sequences: &SequenceSet,
+    detect_leet: bool,
+) -> Vec<usize> {
+    let mut removed = Vec::new();
+
+    loop {
+        let length = password.len();
+        let mut candidates = sequences.candidates(password, detect_leet);
+
+        let non_empty = sequences.iter().enumerate();
+        for (index, sequence) in non_empty.filter(|(_, sequence)| !sequence.is_empty()) {
+            if let Some(candidates) = &candidates {
+                if !candidates[index] {
+                    continue;
+                }
+            }
+
+            let len = sequence.len();
+            let before = password.len();
+
+            // Each sequence could occur multiple times. A removal can only
+            // create a new occurrence that overlaps the removed position, so
+            // the search resumes there instead of at the start. Otherwise a
+            // long repetition of a sequence would take quadratic time.
+            // TODO: ignore case
+            let mut start = 0;
+            while let Some(offset) = password[start..].windows(len).position(|w| {
+                matches_sequence(w, sequence, detect_leet)
+                    || matches_sequence(w, sequence.iter().rev(), detect_leet)
+            }) {
+                let position = start + offset;
+                drop(password.drain(position..(position + len)));
+                if let Some(positions) = positions.as_deref_mut() {
+                    drop(positions.drain(position..(position + len)));
+                }
+                removed.push(index);
+                start = position.saturating_sub(len - 1);
+            }
+
+            // A removal can create new occurrences of other sequences
+            if candidates.is_some() && password.len() != before {
+                candidates = sequences.candidates(password, detect_leet);
+            }
+        }
+
+        if password.len() == length {
+            break;
+        }
+    }
+
+    removed
+}
+
+/// True, if the window of the password matches the sequence. If `detect_leet`
+/// is true, a character of the window also matches if it is a leet substitution
+/// of the character of the sequence.
+fn matches_sequence<'s, I>(window: &[char], sequence: I, detect_leet: bool) -> bool
+where
+    I: IntoIterator<Item = &'s char>,
+{
+    window
+        .iter()
+        .zip(sequence)
+        .all(|(&w, &s)| w == s || (detect_leet && leet_alternatives(w).contains(&s)))
+}
+
+/// The letters a character could be a leet substitution for. A single leet
+/// character can be a substitution for multiple letters, like `1` for `l` and
+/// `i`.
+const fn leet_alternatives(c: char) -> &'static [char] {
+    match c {
+        '@' | '4' => &['a'],
+        '3' => &['e'],
+        '1' => &['l', 'i'],
+        '0' => &['o'],
+        '$' | '5' => &['s'],
+        '7' => &['t'],
+        _ => &[],
+    }
+}
+
+/// Calculates `log_b(e^p)` where b is the base of the logarithm, e is the base
+/// of the exponent and p is the power.
+/// The calculation is done in logspace to avoid an overflow of the power
+/// ( `log_b(e^p) = p * log_b(e)` ).
+///
+/// A power of zero always results in `0.0`, even for an exponent base of zero.
+fn log_power(exp_base: f64, power: usize, log_base: f64) -> f64 {
+    if power == 0 {
+        return 0.0;
+    }
+
+    power as f64 * float::log(exp_base, log_base)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg_attr(feature = "large-wordlist", allow(unused_imports))]
+    use crate::{
+        common_sequences, compare, entropy_bits, entropy_for, log_power, parse_sequences,
+        remove_common_sequences, remove_palindrome, remove_repeating_characters,
+        remove_repeating_patterns, remove_separated_sequences, remove_short_periods,
+        sequence::SequenceSet, CharsetConfig, GroupComposition, GroupKind, PasswordInfo,
+        PasswordInfoBuilder, PasswordStrength, Penalty, DIGIT_CHARS, EMOJI_BASE,
+        EXTENDED_BYTE_BASE, GROUPS, LOWER_CHARS, MAX_BASE, MAX_REASONABLE_LENGTH,
+        OFFLINE_SLOW_HASH_GUESS_RATE, ONLINE_THROTTLED_GUESS_RATE, OTHER_SPECIAL_CHARS,
+        REPLACE_CHARS, SEPARATOR_CHARS, UNICODE_LETTER_BASE, UPPER_CHARS,
+    };
+    use core::cmp::Ordering;
+    use proptest::prelude::*;
+    use std::time::Duration;
+
+    const ERROR_MARGIN: f64 = f64::EPSILON;
+
+    #[test]
+    fn test_entropy() {
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            // Password only uses lowercase => base = 26 with length of 7 characters
+            // https://www.wolframalpha.com/input/?i=log2%2826%5E7%29
+            let password = "letmein";
+            let expected = 7.0 * 26.0_f64.log10() / 2.0_f64.log10();
+            assert!((expected - PasswordInfo::for_password(password).get_entropy()) < ERROR_MARGIN);
+        }
+
+        // Password is empty => entropy = 0.0
+        let password = "";
+        let expected = 0.0;
+        assert!((expected - PasswordInfo::for_password(password).get_entropy()) < ERROR_MARGIN);
+
+        // Password uses upper- and lowercase => base = 2*26 with length of 7 characters
+        // https://www.wolframalpha.com/input/?i=log2%28%282*26%29%5E7%29
+        let password = "LetMeIn";
+        let expected = 7.0 * (2.0 * 26.0_f64).log10() / 2.0_f64.log10();
+        assert!((expected - PasswordInfo::for_password(password).get_entropy()) < ERROR_MARGIN);
+
+        // Password contains one character for each group with length of 6
+        let password = "!_\"aA0";
+        let expected = 6.0
+            * ((REPLACE_CHARS.len()
+                + SEPARATOR_CHARS.len()
+                + OTHER_SPECIAL_CHARS.len()
+                + LOWER_CHARS.len()
+                + UPPER_CHARS.len()
+                + DIGIT_CHARS.len()) as f64)
+                .log10()
+            / 2.0_f64.log10();
+        assert!((expected - PasswordInfo::for_password(password).get_entropy()) < ERROR_MARGIN);
+    }
+
+    #[test]
+    fn test_unicode_letter() {
+        let info = PasswordInfo::for_password("éüß");
+        assert!(info.has_unicode_letter());
+        assert!(!info.has_lower_character());
+        assert_eq!(UNICODE_LETTER_BASE, info.base());
+
+        // A unicode letter must not lower the entropy compared to an ASCII letter
+        let ascii = PasswordInfo::for_password("Strase");
+        let unicode = PasswordInfo::for_password("Straße");
+        assert!(unicode.has_unicode_letter());
+        assert_eq!(ascii.base() + UNICODE_LETTER_BASE, unicode.base());
+        assert!(unicode.get_entropy() > ascii.get_entropy());
+
+        // Unicode characters that are not letters are not part of the group
+        assert!(!PasswordInfo::for_password("1€").has_unicode_letter());
+    }
+
+    #[test]
+    fn test_unclassified() {
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            // The backtick is the only printable ASCII character not part of any
+            // default group
+            let info = PasswordInfo::for_password("secret`");
+            assert!(info.has_unclassified());
+            assert!(!info.has_other_special_character());
+            assert_eq!(26 + 1, info.base());
+        }
+
+        let info = PasswordInfo::for_password("secret~");
+        assert!(!info.has_unclassified());
+
+        // Neither control characters nor unicode characters are unclassified
+        assert!(!PasswordInfo::for_password("a\tb").has_unclassified());
+        assert!(!PasswordInfo::for_bytes(b"a\tb").has_unclassified());
+        assert!(!PasswordInfo::for_password("1€").has_unclassified());
+        assert!(!PasswordInfo::for_password("éüß").has_unclassified());
+    }
+
+    #[test]
+    fn test_control_characters() {
+        for password in &[
+            "pass\tword",
+            "pass\nword",
+            "pass\0word",
+            "\npass\r\nword\u{7f}",
+        ] {
+            let info = PasswordInfo::for_password(password);
+            assert!(info.stripped_control());
+            assert_eq!(
+                PasswordInfo::for_password("password").length(),
+                info.length()
+            );
+            assert_eq!(password.chars().count(), info.original_length());
+            assert_eq!(0, info.base());
+
+            let info = PasswordInfo::for_chars(password.chars());
+            assert!(info.stripped_control());
+            assert_eq!(26, info.base());
+        }
+
+        let info = PasswordInfo::for_password("a\tb\nc\0");
+        assert_eq!(3, info.length());
+        assert_eq!(26, info.base());
+        assert!(!PasswordInfo::for_password("abc").stripped_control());
+
+        // Control bytes are kept and part of the extended group
+        let info = PasswordInfo::for_bytes(b"a\tb\nc\0");
+        assert!(!info.stripped_control());
+        assert!(info.has_extended_byte());
+        assert_eq!(6, info.length());
+        assert_eq!(26 + EXTENDED_BYTE_BASE, info.base());
+    }
+
+    #[test]
+    fn test_mask() {
+        assert_eq!("", PasswordInfo::for_password("").mask());
+        assert_eq!("", PasswordInfo::for_password("password").mask());
+        assert_eq!(
+            "?u?l?d?d?s?s?b?b",
+            PasswordInfo::for_password("Ab12! é😀").mask()
+        );
+
+        // The mask is calculated from the stripped password
+        assert_eq!("?l?d", PasswordInfo::for_password("qwertya1").mask());
+        assert_eq!("?l?u", PasswordInfo::for_chars("aaaB".chars()).mask());
+
+        let info = PasswordInfo::for_password("Tr0ub4dor&3");
+        assert_eq!(2 * info.length(), info.mask().len());
+    }
+
+    #[test]
+    fn test_emoji() {
+        let info = PasswordInfo::for_password("😀");
+        assert!(info.has_emoji());
+        assert!(!info.has_unicode_letter());
+        assert_eq!(EMOJI_BASE, info.base());
+
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            let info = PasswordInfo::for_password("🚀secret☕");
+            assert!(info.has_emoji());
+            assert_eq!(26 + EMOJI_BASE, info.base());
+            assert!(info.get_entropy() > PasswordInfo::for_password("secret").get_entropy());
+        }
+
+        for c in &['\u{2600}', '\u{27BF}', '🌀', '🙏', '🛿', '🤖', '🫠'] {
+            assert!(PasswordInfo::for_password(&c.to_string()).has_emoji());
+        }
+        for password in &["é", "€", "\u{1F650}", "\u{1FA00}", "secret"] {
+            assert!(!PasswordInfo::for_password(password).has_emoji());
+        }
+    }
+
+    #[test]
+    fn test_satisfies_complexity() {
+        for (password, classes) in &[
+            ("", 0),
+            ("xkcd", 1),
+            ("xkcd7", 2),
+            ("Xkcd7", 3),
+            ("Xkcd7&", 4),
+            ("Xkcd7-", 4),
+            ("Xkcd7#", 4),
+        ] {
+            let info = PasswordInfo::for_password(password);
+            assert_eq!(*classes == 4, info.satisfies_complexity(), "{password}");
+            assert!(info.satisfies_complexity_of(*classes), "{}", password);
+            assert!(!info.satisfies_complexity_of(classes + 1), "{}", password);
+        }
+        assert!(PasswordInfo::for_password("\u{e9}\u{e8}").satisfies_complexity_of(0));
+    }
+
+    #[test]
+    fn test_groups() {
+        let names: Vec<&str> = GROUPS.iter().map(|(name, _)| *name).collect();
+        assert_eq!(
+            vec![
+                "replace",
+                "separator",
+                "other special",
+                "lower",
+                "upper",
+                "digit"
+            ],
+            names
+        );
+
+        // One character of each group
+        let password: String = GROUPS
+            .iter()
+            .filter_map(|(_, chars)| chars.chars().next())
+            .collect();
+        let info = PasswordInfo::for_password(&password);
+        let all: String = GROUPS.iter().map(|(_, chars)| *chars).collect();
+        assert_eq!(all, info.charset());
+        assert_eq!(94, info.base());
+    }
+
+    #[test]
+    fn test_charset() {
+        assert_eq!("", PasswordInfo::for_password("").charset());
+        #[cfg(not(feature = "large-wordlist"))]
+        assert_eq!(LOWER_CHARS, PasswordInfo::for_password("letmein").charset());
+
+        let info = PasswordInfo::for_password("Tr0ub4dor&3");
+        let expected = [REPLACE_CHARS, LOWER_CHARS, UPPER_CHARS, DIGIT_CHARS].concat();
+        assert_eq!(expected, info.charset());
+        assert_eq!(usize::from(info.base()), info.charset().chars().count());
+    }
+
+    #[test]
+    fn test_stripped() {
+        let info = PasswordInfo::for_password("Tr0ub4dor&3");
+        assert!(!info.stripped_palindrome());
+        assert!(!info.stripped_sequence());
+        assert!(!info.stripped_repeat());
+
+        let info = PasswordInfo::for_password("abcXcba");
+        assert!(info.stripped_palindrome());
+        assert!(!info.stripped_sequence());
+        assert!(!info.stripped_repeat());
+
+        let info = PasswordInfo::for_password("myqwertyX");
+        assert!(!info.stripped_palindrome());
+        assert!(info.stripped_sequence());
+        assert!(!info.stripped_repeat());
+
+        let info = PasswordInfo::for_password("heeeelo");
+        assert!(!info.stripped_palindrome());
+        assert!(!info.stripped_sequence());
+        assert!(info.stripped_repeat());
+
+        assert!(PasswordInfo::for_password("xyzxyzxyz").stripped_repeat());
+        assert!(PasswordInfo::for_chars("heeeelo".chars()).stripped_repeat());
+        assert!(!PasswordInfo::for_chars("helo".chars()).stripped_repeat());
+    }
+
+    #[test]
+    fn test_reduction_ratio() {
+        let info = PasswordInfo::for_password("");
+        assert_eq!(0, info.original_length());
+        assert!((1.0 - info.reduction_ratio()).abs() < ERROR_MARGIN);
+
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            let info = PasswordInfo::for_password("letmein");
+            assert_eq!(7, info.original_length());
+            assert!((1.0 - info.reduction_ratio()).abs() < ERROR_MARGIN);
+        }
+
+        // The palindrome is cut in half
+        let info = PasswordInfo::for_password("abccba");
+        assert_eq!(6, info.original_length());
+        assert!((0.5 - info.reduction_ratio()).abs() < ERROR_MARGIN);
+
+        let info = PasswordInfo::for_password("password");
+        assert_eq!(8, info.original_length());
+        assert!(info.reduction_ratio().abs() < ERROR_MARGIN);
+
+        let info = PasswordInfo::for_chars("aabb".chars());
+        assert_eq!(4, info.original_length());
+        assert!((0.5 - info.reduction_ratio()).abs() < ERROR_MARGIN);
+    }
+
+    #[test]
+    fn test_word_count() {
+        assert_eq!(0, PasswordInfo::for_password("").word_count());
+        assert_eq!(0, PasswordInfo::for_password("   ").word_count());
+        assert_eq!(1, PasswordInfo::for_password("Tr0ub4dor&3").word_count());
+        assert_eq!(
+            4,
+            PasswordInfo::for_password("correct horse battery staple").word_count()
+        );
+        assert_eq!(
+            2,
+            PasswordInfo::for_password(" correct \t horse ").word_count()
+        );
+        // Other separators do not split words
+        assert_eq!(1, PasswordInfo::for_password("correct-horse").word_count());
+        assert_eq!(
+            2,
+            PasswordInfo::for_chars("correct horse".chars()).word_count()
+        );
+    }
+
+    #[test]
+    fn test_distinct_character_count() {
+        assert_eq!(0, PasswordInfo::for_password("").distinct_character_count());
+        #[cfg(not(feature = "large-wordlist"))]
+        assert_eq!(
+            6,
+            PasswordInfo::for_password("letmein").distinct_character_count()
+        );
+
+        let info = PasswordInfo::for_password("abab!abab");
+        assert_eq!(9, info.length());
+        assert_eq!(3, info.distinct_character_count());
+
+        // Only the stripped password is counted
+        let info = PasswordInfo::for_password("ababab!");
+        assert_eq!(3, info.length());
+        assert_eq!(3, info.distinct_character_count());
+
+        let info = PasswordInfo::for_password("abcbXa");
+        assert_eq!(6, info.length());
+        assert_eq!(4, info.distinct_character_count());
+    }
+
+    #[test]
+    fn test_entropy_base() {
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            // 7 * log2(26) bits
+            let info = PasswordInfo::for_password("letmein");
+            assert!((info.get_entropy() - info.get_entropy_base(2.0)).abs() < ERROR_MARGIN);
+
+            // 7 * ln(26) nats
+            let expected = 7.0 * 26.0_f64.ln();
+            let actual = info.get_entropy_base(core::f64::consts::E);
+            assert!((expected - actual).abs() < 1e-12);
+
+            // 7 * log10(26) dits
+            let expected = 7.0 * 26.0_f64.log10();
+            assert!((expected - info.get_entropy_base(10.0)).abs() < 1e-12);
+
+            // Invalid logarithm bases
+            assert!(info.get_entropy_base(1.0).is_nan());
+            assert!(info.get_entropy_base(0.0).is_nan());
+            assert!(info.get_entropy_base(-2.0).is_nan());
+            assert!(info.get_entropy_base(f64::NAN).is_nan());
+        }
+
+        // The empty password has an entropy of zero in every valid base
+        let info = PasswordInfo::for_password("");
+        assert!(info.get_entropy_base(10.0).abs() < ERROR_MARGIN);
+    }
+
+    #[test]
+    fn test_meets_entropy() {
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            // 7 * log2(26) = 32.9 bits
+            let info = PasswordInfo::for_password("letmein");
+            assert!(info.meets_entropy(0.0));
+            assert!(info.meets_entropy(32.0));
+            assert!(!info.meets_entropy(33.0));
+        }
+
+        assert!(PasswordInfo::for_password("").meets_entropy(0.0));
+    }
+
+    #[test]
+    fn test_suggestions() {
+        let suggestions = PasswordInfo::for_password("").suggestions();
+        assert_eq!(7, suggestions.len());
+
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            let suggestions = PasswordInfo::for_password("letmein").suggestions();
+            assert!(suggestions.contains(&"Use a longer password."));
+            assert!(!suggestions.contains(&"Add a lowercase letter."));
+            assert!(suggestions.contains(&"Add an uppercase letter."));
+            assert!(suggestions.contains(&"Add a digit."));
+        }
+
+        let suggestions = PasswordInfo::for_password("This-Is#A!Secret1").suggestions();
+        assert!(suggestions.is_empty());
+    }
+
+    #[test]
+    fn test_suspiciously_long() {
+        let info = PasswordInfo::for_password("Tr0ub4dor&3");
+        assert!(!info.is_suspiciously_long());
+        assert!(!info.is_too_short());
+
+        // Pseudo random printable characters without a repeating pattern
+        let mut seed: u32 = 42;
+        let password: String = (0..200)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                char::from(b'!' + (seed >> 16) as u8 % 94)
+            })
+            .collect();
+        let info = PasswordInfo::for_password(&password);
+        assert!(info.length() > MAX_REASONABLE_LENGTH);
+        assert!(info.is_suspiciously_long());
+        assert_eq!(PasswordStrength::Strong, info.strength());
+        assert!(info
+            .suggestions()
+            .contains(&"Check the password, it is unusually long."));
+    }
+
+    #[test]
+    fn test_combined_entropy() {
+        assert!(PasswordInfo::combined_entropy(&[]).abs() < ERROR_MARGIN);
+
+        let pin = PasswordInfo::for_password("8412").get_entropy();
+        let word = PasswordInfo::for_password("Tr0ub4dor&3").get_entropy();
+        let combined = PasswordInfo::combined_entropy(&["8412", "Tr0ub4dor&3"]);
+        assert!((pin + word - combined).abs() < ERROR_MARGIN);
+
+        // The concatenation joins a common sequence across the parts
+        assert!(PasswordInfo::for_password("qwerty").get_entropy().abs() < ERROR_MARGIN);
+        assert!(PasswordInfo::combined_entropy(&["qwe", "rty"]) > 0.0);
+    }
+
+    #[test]
+    fn test_for_passwords() {
+        let passwords = ["", "letmein", "password", "Tr0ub4dor&3"];
+        let infos: Vec<PasswordInfo> =
+            PasswordInfo::for_passwords(passwords.iter().copied()).collect();
+
+        assert_eq!(passwords.len(), infos.len());
+        for (password, info) in passwords.iter().zip(&infos) {
+            let expected = PasswordInfo::for_password(password);
+            assert_eq!(expected.length(), info.length());
+            assert_eq!(expected.base(), info.base());
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_for_passwords() {
+        let passwords = ["", "letmein", "password", "Tr0ub4dor&3"];
+        let infos = PasswordInfo::par_for_passwords(&passwords);
+
+        assert_eq!(passwords.len(), infos.len());
+        for (password, info) in passwords.iter().zip(&infos) {
+            let expected = PasswordInfo::for_password(password);
+            assert_eq!(expected.length(), info.length());
+            assert_eq!(expected.base(), info.base());
+        }
+    }
+
+    #[test]
+    fn test_for_chars() {
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            for password in &["", "letmein", "LetMeIn", "!_\"aA0", "Straße", "aabbcc"] {
+                let expected = PasswordInfo::for_password(password);
+                let actual = PasswordInfo::for_chars(password.chars());
+                assert_eq!(expected.length(), actual.length());
+                assert_eq!(expected.base(), actual.base());
+                assert!((expected.get_entropy() - actual.get_entropy()).abs() < ERROR_MARGIN);
+            }
+        }
+
+        // Common sequences and palindromes are not removed
+        let info = PasswordInfo::for_chars("password".chars());
+        assert_eq!("pasword".len(), info.length());
+        let info = PasswordInfo::for_chars("abcba".chars());
+        assert_eq!(5, info.length());
+    }
+
+    #[test]
+    fn test_strength() {
+        assert_eq!(
+            PasswordStrength::VeryWeak,
+            PasswordInfo::for_password("").strength()
+        );
+        assert_eq!(
+            PasswordStrength::VeryWeak,
+            PasswordInfo::for_password("password").strength()
+        );
+        // 8 * log2(36) = 41.4 bits
+        #[cfg(not(feature = "large-wordlist"))]
+        assert_eq!(
+            PasswordStrength::Reasonable,
+            PasswordInfo::for_password("letmein2").strength()
+        );
+        // 14 * log2(62) = 83.4 bits
+        assert_eq!(
+            PasswordStrength::Strong,
+            PasswordInfo::for_password("ThisIsASecret1").strength()
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_has_seperator_character() {
+        let info = PasswordInfo::for_password("correct horse");
+        assert!(info.has_separator_character());
+        assert_eq!(
+            info.has_separator_character(),
+            info.has_seperator_character()
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "entropy=0.0 bits, length=0, base=0, strength=VeryWeak",
+            PasswordInfo::for_password("").to_string()
+        );
+        #[cfg(not(feature = "large-wordlist"))]
+        assert_eq!(
+            "entropy=32.9 bits, length=7, base=26, strength=Weak",
+            PasswordInfo::for_password("letmein").to_string()
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        for password in &["", "letmein", "Tr0ub4dor&3"] {
+            let expected = PasswordInfo::for_password(password).get_entropy();
+            let parsed: PasswordInfo = password.parse().expect("parsing is infallible");
+            let converted = PasswordInfo::from(*password);
+            assert!((expected - parsed.get_entropy()).abs() < f64::EPSILON);
+            assert!((expected - converted.get_entropy()).abs() < f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_per_char_entropy() {
+        assert!(PasswordInfo::for_password("").per_char_entropy().is_empty());
+
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            let info = PasswordInfo::for_password("letmein");
+            let bits = 26.0_f64.log2();
+            for entropy in info.per_char_entropy() {
+                assert!((bits - entropy).abs() < ERROR_MARGIN);
+            }
+        }
+
+        // `qwerty` and the second `a` are removed
+        let info = PasswordInfo::for_password("xaaqwertyz");
+        let bits = 26.0_f64.log2();
+        let expected = [bits, bits, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, bits];
+        let entropies = info.per_char_entropy();
+        assert_eq!(expected.len(), entropies.len());
+        for (expected, entropy) in expected.iter().zip(&entropies) {
+            assert!((expected - entropy).abs() < ERROR_MARGIN);
+        }
+        let sum: f64 = entropies.iter().sum();
+        assert!((info.get_entropy() - sum).abs() < 1e-9);
+
+        // The first `q` is removed as part of `qwerty`, the last one is kept
+        let info = PasswordInfo::for_password("qwertyq");
+        let bits = 26.0_f64.log2();
+        let expected = [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, bits];
+        let entropies = info.per_char_entropy();
+        assert_eq!(expected.len(), entropies.len());
+        for (expected, entropy) in expected.iter().zip(&entropies) {
+            assert!((expected - entropy).abs() < ERROR_MARGIN);
+        }
+
+        let info = PasswordInfo::for_chars("aab".chars());
+        let bits = 26.0_f64.log2();
+        let entropies = info.per_char_entropy();
+        assert_eq!(3, entropies.len());
+        assert!(entropies[1].abs() < ERROR_MARGIN);
+        assert!((bits - entropies[2]).abs() < ERROR_MARGIN);
+    }
+
+    #[test]
+    fn test_empty_password() {
+        let infos = [
+            PasswordInfo::for_password(""),
+            PasswordInfo::for_bytes(&[]),
+            PasswordInfo::for_chars("".chars()),
+            PasswordInfo::builder()
+                .include_pattern_entropy(true)
+                .for_password(""),
+        ];
+
+        for info in &infos {
+            assert_eq!(0, info.length());
+            assert_eq!(0, info.original_length());
+            assert_eq!(0, info.base());
+            assert_eq!(0, info.distinct_character_count());
+            assert_eq!(0, info.word_count());
+            assert!(info.get_entropy().abs() < f64::EPSILON);
+            assert!(info.raw_entropy().abs() < f64::EPSILON);
+            assert!(info.entropy_per_char().abs() < f64::EPSILON);
+            assert!(info.max_possible_entropy().abs() < f64::EPSILON);
+            assert!(info.get_shannon_entropy().abs() < f64::EPSILON);
+            assert!((info.reduction_ratio() - 1.0).abs() < f64::EPSILON);
+            assert_eq!(1, info.guesses());
+            assert_eq!(PasswordStrength::VeryWeak, info.strength());
+
+            for &kind in GroupKind::ALL {
+                assert!(!info.has_group(kind));
+            }
+            assert!(info.present_groups().is_empty());
+            assert_eq!(GroupComposition::default(), info.group_composition());
+
+            assert!(!info.stripped_palindrome());
+            assert!(!info.stripped_sequence());
+            assert!(!info.stripped_repeat());
+            assert!(!info.stripped_control());
+            assert!(info.matched_sequences().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_entropy_bits() {
+        for password in &["", "letmein", "ThisIsASecret", "Tr0ub4dor&3"] {
+            let expected = PasswordInfo::for_password(password).get_entropy();
+            assert!((expected - entropy_bits(password)).abs() < ERROR_MARGIN);
+        }
+    }
+
+    #[test]
+    fn test_entropy_for() {
+        assert!(entropy_for(0, 0).abs() < ERROR_MARGIN);
+        assert!(entropy_for(0, 10).abs() < ERROR_MARGIN);
+        assert!(entropy_for(94, 0).abs() < ERROR_MARGIN);
+        assert!(entropy_for(1, 10).abs() < ERROR_MARGIN);
+        assert!((entropy_for(2, 10) - 10.0).abs() < ERROR_MARGIN);
+
+        let info = PasswordInfo::for_password("Tr0ub4dor&3");
+        let expected = info.get_entropy();
+        assert!((expected - entropy_for(info.base(), info.length())).abs() < ERROR_MARGIN);
+    }
+
+    #[test]
+    fn test_entropy_gain_from() {
+        let empty = PasswordInfo::for_password("");
+        let weak = PasswordInfo::for_password("letmein");
+        let strong = PasswordInfo::for_password("Tr0ub4dor&3");
+
+        assert!((weak.entropy_gain_from(&empty) - weak.get_entropy()).abs() < ERROR_MARGIN);
+        assert!(strong.entropy_gain_from(&weak) > 0.0);
+        assert!(weak.entropy_gain_from(&strong) < 0.0);
+        assert!(strong.entropy_gain_from(&strong).abs() < ERROR_MARGIN);
+
+        // Appending a common sequence gains nothing
+        let appended = PasswordInfo::for_password("letmeinqwerty");
+        assert!(appended.entropy_gain_from(&weak).abs() < ERROR_MARGIN);
+    }
+
+    #[test]
+    fn test_compare() {
+        let weak = PasswordInfo::for_password("letmein");
+        let strong = PasswordInfo::for_password("Tr0ub4dor&3");
+        assert!(strong.is_stronger_than(&weak));
+        assert!(!weak.is_stronger_than(&strong));
+        assert!(!weak.is_stronger_than(&weak));
+        assert_eq!(Ordering::Less, compare("letmein", "Tr0ub4dor&3"));
+        assert_eq!(Ordering::Equal, compare("letmein", "letmein"));
+
+        // 16^2 and 4^4 have the same entropy, so the longer one wins
+        let config = CharsetConfig {
+            replace: "",
+            separator: "",
+            other_special: "",
+            lower: "abcdefghijklmnop",
+            upper: "",
+            digit: "0123",
+            ..CharsetConfig::default()
+        };
+        let short = PasswordInfo::for_password_with_charset("pa", &config);
+        let long = PasswordInfo::for_password_with_charset("0312", &config);
+        assert!((short.get_entropy() - long.get_entropy()).abs() < ERROR_MARGIN);
+        assert_eq!(Ordering::Greater, long.compare_strength(&short));
+        assert!(long.is_stronger_than(&short));
+    }
+
+    #[test]
+    fn test_for_bytes() {
+        // ASCII bytes are classified like the characters
+        let expected = PasswordInfo::for_password("Tr0ub4dor&3");
+        let info = PasswordInfo::for_bytes(b"Tr0ub4dor&3");
+        assert_eq!(expected.length(), info.length());
+        assert_eq!(expected.base(), info.base());
+        assert!(!info.has_extended_byte());
+        assert!((expected.get_entropy() - info.get_entropy()).abs() < ERROR_MARGIN);
+
+        // Bytes beyond ASCII are neither unicode letters nor part of a group
+        let info = PasswordInfo::for_bytes(&[b'a', 0xE9, 0xFF, 0x80]);
+        assert!(info.has_extended_byte());
+        assert!(!info.has_unicode_letter());
+        assert_eq!(4, info.length());
+        assert_eq!(26 + EXTENDED_BYTE_BASE, info.base());
+        assert_eq!(3, info.group_composition().other);
+        assert!(
+            (log_power(f64::from(info.base()), 4, 2.0) - info.get_entropy()).abs() < ERROR_MARGIN
+        );
+
+        // Common sequences are still removed
+        let info = PasswordInfo::for_bytes(b"password\xFF");
+        assert_eq!(1, info.length());
+
+        assert_eq!(0, PasswordInfo::for_bytes(&[]).base());
+    }
+
+    #[test]
+    fn test_chars_needed_for() {
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            let info = PasswordInfo::for_password("letmein");
+            let needed = info.chars_needed_for(60.0);
+            assert_eq!(6, needed);
+            let per_char = 26.0_f64.log2();
+            assert!(info.get_entropy() + needed as f64 * per_char >= 60.0);
+            assert!(info.get_entropy() + (needed - 1) as f64 * per_char < 60.0);
+
+            assert_eq!(0, info.chars_needed_for(info.get_entropy()));
+        }
+        let info = PasswordInfo::for_password("Tr0ub4dor&3");
+        assert_eq!(usize::MAX, info.chars_needed_for(f64::NAN));
+        assert_eq!(usize::MAX, info.chars_needed_for(f64::INFINITY));
+        assert_eq!(0, info.chars_needed_for(f64::NEG_INFINITY));
+        assert_eq!(
+            usize::MAX,
+            PasswordInfo::for_password("").chars_needed_for(1.0)
+        );
+        assert_eq!(0, PasswordInfo::for_password("").chars_needed_for(0.0));
+    }
+
+    #[test]
+    fn test_max_base() {
+        assert_eq!(94, MAX_BASE);
+        let sum: usize = GROUPS.iter().map(|(_, chars)| chars.chars().count()).sum();
+        assert_eq!(usize::from(MAX_BASE), sum);
+        assert_eq!(MAX_BASE, PasswordInfo::for_password("!_\"aA0").base());
+    }
+
+    #[test]
+    fn test_max_possible_entropy() {
+        assert!(PasswordInfo::for_password("").max_possible_entropy().abs() < ERROR_MARGIN);
+
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            let info = PasswordInfo::for_password("letmein");
+            let expected = 7.0 * 94.0_f64.log2();
+            assert!((expected - info.max_possible_entropy()).abs() < ERROR_MARGIN);
+            assert!(info.get_entropy() < info.max_possible_entropy());
+        }
+
+        // Every group is present
+        let info = PasswordInfo::for_password("!_\"aA0");
+        assert!((info.get_entropy() - info.max_possible_entropy()).abs() < ERROR_MARGIN);
+    }
+
+    #[test]
+    fn test_entropy_per_char() {
+        assert!(PasswordInfo::for_password("").entropy_per_char().abs() < ERROR_MARGIN);
+        assert!(
+            PasswordInfo::for_password("password")
+                .entropy_per_char()
+                .abs()
+                < ERROR_MARGIN
+        );
+
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            let info = PasswordInfo::for_password("letmein");
+            assert!((26.0_f64.log2() - info.entropy_per_char()).abs() < ERROR_MARGIN);
+        }
+
+        let info = PasswordInfo::for_password("Tr0ub4dor&3");
+        let expected = info.get_entropy() / info.length() as f64;
+        assert!((expected - info.entropy_per_char()).abs() < ERROR_MARGIN);
+    }
+
+    #[test]
+    fn test_entropy_opt() {
+        assert_eq!(Some(0.0), PasswordInfo::for_password("").entropy_opt());
+        assert_eq!(Some(0.0), PasswordInfo::for_chars("".chars()).entropy_opt());
+        for password in &["password", "qwerty1234", "\t\n"] {
+            assert_eq!(None, PasswordInfo::for_password(password).entropy_opt());
+        }
+
+        let info = PasswordInfo::for_password("Tr0ub4dor&3");
+        assert_eq!(Some(info.get_entropy()), info.entropy_opt());
+
+        // A single remaining character has a known entropy
+        let info = PasswordInfo::for_password("aaaa");
+        assert_eq!(Some(info.get_entropy()), info.entropy_opt());
+    }
+
+    #[test]
+    fn test_entropy_clamped() {
+        let info = PasswordInfo::for_password("Tr0ub4dor&3");
+        let entropy = info.get_entropy();
+
+        assert!((entropy - info.get_entropy_clamped(256.0)).abs() < ERROR_MARGIN);
+        assert!((50.0 - info.get_entropy_clamped(50.0)).abs() < ERROR_MARGIN);
+        assert!(info.get_entropy_clamped(-1.0).abs() < ERROR_MARGIN);
+        assert!((entropy - info.get_entropy_clamped(f64::NAN)).abs() < ERROR_MARGIN);
+        assert!(
+            PasswordInfo::for_password("")
+                .get_entropy_clamped(256.0)
+                .abs()
+                < ERROR_MARGIN
+        );
+    }
+
+    #[test]
+    fn test_entropy_range() {
+        let (lower, upper) = PasswordInfo::for_password("").entropy_range();
+        assert!(lower.abs() < ERROR_MARGIN);
+        assert!(upper.abs() < ERROR_MARGIN);
+
+        let info = PasswordInfo::for_password("x7#q");
+        let (lower, upper) = info.entropy_range();
+        assert!((lower - upper).abs() < ERROR_MARGIN);
+
+        let info = PasswordInfo::for_password("Tr0ub4dor&3aaaa");
+        let (lower, upper) = info.entropy_range();
+        assert!((info.get_entropy() - lower).abs() < ERROR_MARGIN);
+        let expected = 15.0 * f64::from(info.base()).log2();
+        assert!((expected - upper).abs() < ERROR_MARGIN);
+
+        // The stripped control characters are never random characters
+        let (lower, upper) = PasswordInfo::for_password("\0\u{1}\u{2}ab").entropy_range();
+        let expected = 2.0 * 26.0_f64.log2();
+        assert!((expected - lower).abs() < ERROR_MARGIN);
+        assert!((expected - upper).abs() < ERROR_MARGIN);
+
+        let (lower, upper) = PasswordInfo::for_password("password").entropy_range();
+        assert!(lower.abs() < ERROR_MARGIN);
+        assert!(upper.abs() < ERROR_MARGIN);
+
+        // The characters of `password` count as random, not as fixed bits
+        let info = PasswordInfoBuilder::new()
+            .sequence_penalty_mode(Penalty::FixedBits)
+            .for_password("password7x");
+        assert_eq!(2, info.length());
+        assert_eq!(36, info.base());
+        let (lower, upper) = info.entropy_range();
+        let sequence_bits = (common_sequences().len() as f64).log2();
+        assert!((2.0 * 36.0_f64.log2() + sequence_bits - lower).abs() < ERROR_MARGIN);
+        assert!((10.0 * 36.0_f64.log2() - upper).abs() < ERROR_MARGIN);
+
+        let info = PasswordInfoBuilder::new()
+            .sequence_penalty_mode(Penalty::FixedBits)
+            .for_password("password");
+        let (lower, upper) = info.entropy_range();
+        assert!(lower > 0.0);
+        assert!((lower - upper).abs() < ERROR_MARGIN);
+    }
+
+    #[test]
+    fn test_raw_entropy() {
+        assert!(PasswordInfo::for_password("").raw_entropy().abs() < ERROR_MARGIN);
+
+        // 26 + 10 = 36 characters, 9 of them in the raw password
+        let info = PasswordInfo::for_password("password1");
+        assert_eq!(0, info.length());
+        assert!((9.0 * 36.0_f64.log2() - info.raw_entropy()).abs() < ERROR_MARGIN);
+
+        let info = PasswordInfo::for_password("Tr0ub4dor&3");
+        assert!((info.get_entropy() - info.raw_entropy()).abs() < ERROR_MARGIN);
+
+        let info = PasswordInfo::for_chars("aaab".chars());
+        assert!((4.0 * 26.0_f64.log2() - info.raw_entropy()).abs() < ERROR_MARGIN);
+
+        // The stripped control characters are not part of the keyspace
+        let expected = 2.0 * 26.0_f64.log2();
+        let info = PasswordInfo::for_password("\0\u{1}\u{2}ab");
+        assert_eq!(5, info.original_length());
+        assert!((expected - info.raw_entropy()).abs() < ERROR_MARGIN);
+        let info = PasswordInfo::for_chars("\0\u{1}\u{2}ab".chars());
+        assert!((expected - info.raw_entropy()).abs() < ERROR_MARGIN);
+    }
+
+    #[cfg(all(feature = "std", not(feature = "large-wordlist")))]
+    #[test]
+    fn test_entropy_weighted() {
+        use std::collections::HashMap;
+
+        let info = PasswordInfo::for_password("letmein");
+        let uniform = info.get_entropy_weighted(&HashMap::new());
+        assert!((info.get_entropy() - uniform).abs() < ERROR_MARGIN);
+
+        let freqs: HashMap<char, f64> = [('e', 0.5), ('x', 0.5), ('t', 0.0)]
+            .iter()
+            .copied()
+            .collect();
+        let expected = 5.0 * 26.0_f64.log2() + 2.0;
+        assert!((expected - info.get_entropy_weighted(&freqs)).abs() < ERROR_MARGIN);
+    }
+
+    #[test]
+    fn test_guesses() {
+        assert_eq!(1, PasswordInfo::for_password("").guesses());
+        // 26^7 guesses
+        #[cfg(not(feature = "large-wordlist"))]
+        assert_eq!(
+            8_031_810_176,
+            PasswordInfo::for_password("letmein").guesses()
+        );
+
+        let info = PasswordInfo::for_password("Tr0ub4dor&3 correct horse battery staple");
+        assert!(info.get_entropy() > 128.0);
+        assert_eq!(u128::MAX, info.guesses());
+    }
+
+    #[test]
+    fn test_crack_time() {
+        // Password is empty => 2^0 / 2 = 0.5 guesses
+        let info = PasswordInfo::for_password("");
+        assert_eq!(Duration::from_millis(500), info.crack_time(1.0));
+        assert_eq!(Duration::from_millis(50), info.crack_time(10.0));
+
+        // 7 * log2(26) bits => 26^7 / 2 guesses
+        let info = PasswordInfo::for_password("letmein");
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            let expected = 26.0_f64.powi(7) / 2.0 / OFFLINE_SLOW_HASH_GUESS_RATE;
+            assert!(
+                (expected - info.crack_time(OFFLINE_SLOW_HASH_GUESS_RATE).as_secs_f64()).abs()
+                    < 1e-3
+            );
+        }
+
+        // Invalid guess rates
+        assert_eq!(Duration::MAX, info.crack_time(0.0));
+        assert_eq!(Duration::MAX, info.crack_time(-1.0));
+        assert_eq!(Duration::MAX, info.crack_time(f64::NAN));
+
+        // Too many guesses to be represented by a duration
+        let info = PasswordInfo::for_password("ThisIsASecret1");
+        assert_eq!(Duration::MAX, info.crack_time(ONLINE_THROTTLED_GUESS_RATE));
+    }
+
+    #[test]
+    fn test_shannon_entropy() {
+        // Password is empty => entropy = 0.0
+        assert!(PasswordInfo::for_password("").get_shannon_entropy().abs() < ERROR_MARGIN);
+
+        // Only one distinct character => entropy = 0.0
+        assert!(PasswordInfo::for_password("a").get_shannon_entropy().abs() < ERROR_MARGIN);
+
+        // Four distinct characters with the same frequency => entropy = log2(4)
+        let expected = 2.0;
+        let actual = PasswordInfo::for_password("wxyz").get_shannon_entropy();
+        assert!((expected - actual).abs() < ERROR_MARGIN);
+
+        // "abab" has a high length but only two distinct characters => log2(2)
+        let expected = 1.0;
+        let actual = PasswordInfo::for_password("abab").get_shannon_entropy();
+        assert!((expected - actual).abs() < ERROR_MARGIN);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let info = PasswordInfo::for_password("!_\"aA0");
+        let json = serde_json::to_string(&info).expect("serialization failed");
+        assert!(json.contains("\"has_separator\":true"));
+
+        let deserialized: PasswordInfo =
+            serde_json::from_str(&json).expect("deserialization failed");
+        assert_eq!(info.length(), deserialized.length());
+        assert_eq!(info.original_length(), deserialized.original_length());
+        assert_eq!(
+            info.distinct_character_count(),
+            deserialized.distinct_character_count()
+        );
+        assert_eq!(info.base(), deserialized.base());
+        assert!(deserialized.has_replace_character());
+        assert!(deserialized.has_separator_character());
+        assert!(deserialized.has_other_special_character());
+        assert!(deserialized.has_lower_character());
+        assert!(deserialized.has_upper_character());
+        assert!(deserialized.has_digit());
+        assert!((info.get_entropy() - deserialized.get_entropy()).abs() < ERROR_MARGIN);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::{Zeroize, ZeroizeOnDrop};
+
+        // The info is zeroized when it is dropped.
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<PasswordInfo>();
+
+        let mut info = PasswordInfo::for_password("!_\"aA0é");
+        info.zeroize();
+        assert_eq!(0, info.length());
+        assert_eq!(0, info.original_length());
+        assert_eq!(0, info.distinct_character_count());
+        assert_eq!(0, info.base());
+        assert!(!info.has_replace_character());
+        assert!(!info.has_separator_character());
+        assert!(!info.has_other_special_character());
+        assert!(!info.has_lower_character());
+        assert!(!info.has_upper_character());
+        assert!(!info.has_digit());
+        assert!(!info.has_unicode_letter());
+        assert!(!info.has_unclassified());
+        assert!(info.get_shannon_entropy().abs() < ERROR_MARGIN);
+    }
+
+    #[test]
+    fn test_log_power() {
+        // https://www.wolframalpha.com/input/?i=log2%2826%5E7%29
+        let expected = 7.0 * 26.0_f64.log10() / 2.0_f64.log10();
+        assert!((expected - log_power(26.0, 7, 2.0)).abs() < ERROR_MARGIN);
 
         // https://www.wolframalpha.com/input/?i=log2%280%5E42%29
         assert!(log_power(0.0, 42, 2.0).is_infinite());
@@ -348,54 +3815,548 @@ mod tests {
         // https://www.wolframalpha.com/input/?i=log2%285%5E0%29
         let expected = 0.0;
         assert!((expected - log_power(5.0, 0, 2.0)) < ERROR_MARGIN);
+        assert!((expected - log_power(0.0, 0, 2.0)).abs() < ERROR_MARGIN);
+
+        // Large powers are calculated without accumulating rounding errors
+        let expected = 1_000_000.0 * 94.0_f64.log2();
+        assert!((expected - log_power(94.0, 1_000_000, 2.0)).abs() < ERROR_MARGIN * expected);
+    }
+
+    #[test]
+    fn test_remove_separated_sequences() {
+        let sequences = SequenceSet::new(&common_sequences());
+        for (password, expected, count) in &[
+            ("", "", 0),
+            ("p-a-s-s-w-o-r-d", "-------", 1),
+            ("x_qwe.rty_y", "x_._y", 1),
+            ("q w e r t y 1 2 3 4", "         ", 2),
+            ("a-b-c", "a-b-c", 0),
+        ] {
+            let mut pw: Vec<char> = password.chars().collect();
+            let removed =
+                remove_separated_sequences(&mut pw, None, SEPARATOR_CHARS, &sequences, false);
+            assert_eq!(*expected, pw.iter().collect::<String>());
+            assert_eq!(*count, removed.len());
+        }
+    }
+
+    #[test]
+    fn test_parse_sequences() {
+        let file = "# comment\nasdf\n\n   \r\nasdf ;lkj\r\n#qwerty\n1234";
+        assert_eq!(vec!["asdf", "asdf ;lkj", "1234"], parse_sequences(file));
+
+        let sequences = common_sequences();
+        assert!(sequences.contains(&"password"));
+        assert!(sequences.contains(&"asdf ;lkj"));
+        assert!(!sequences
+            .iter()
+            .any(|sequence| sequence.starts_with('#') || sequence.trim().is_empty()));
+    }
+
+    #[cfg(feature = "large-wordlist")]
+    #[test]
+    fn test_large_wordlist() {
+        assert!(common_sequences().contains(&"dragon"));
+        assert_eq!(0, PasswordInfo::for_password("dragon").length());
     }
 
     #[test]
     fn test_remove_common_sequences() {
         let mut password: Vec<char> = "password".chars().collect();
-        remove_common_sequences(&mut password);
+        assert_eq!(
+            1,
+            remove_common_sequences(
+                &mut password,
+                None,
+                &SequenceSet::new(&common_sequences()),
+                false
+            )
+            .len()
+        );
         let expected: Vec<char> = Vec::new();
         assert_eq!(expected, password);
 
         let mut password: Vec<char> = "asdf|password|asdf|qwerty".chars().collect();
-        remove_common_sequences(&mut password);
+        assert_eq!(
+            4,
+            remove_common_sequences(
+                &mut password,
+                None,
+                &SequenceSet::new(&common_sequences()),
+                false
+            )
+            .len()
+        );
         let expected: Vec<char> = "|||".chars().collect();
         assert_eq!(expected, password);
 
         let mut password: Vec<char> = "1234ThisIsUntouched!asdf".chars().collect();
-        remove_common_sequences(&mut password);
+        assert_eq!(
+            2,
+            remove_common_sequences(
+                &mut password,
+                None,
+                &SequenceSet::new(&common_sequences()),
+                false
+            )
+            .len()
+        );
+        let expected: Vec<char> = "ThisIsUntouched!".chars().collect();
+        assert_eq!(expected, password);
+    }
+
+    #[test]
+    fn test_remove_reversed_sequences() {
+        let mut password: Vec<char> = "fdsa|;lkj|drowssap|ytrewq".chars().collect();
+        assert_eq!(
+            4,
+            remove_common_sequences(
+                &mut password,
+                None,
+                &SequenceSet::new(&common_sequences()),
+                false
+            )
+            .len()
+        );
+        let expected: Vec<char> = "|||".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "X|dr0wss@p".chars().collect();
+        assert_eq!(
+            1,
+            remove_common_sequences(
+                &mut password,
+                None,
+                &SequenceSet::new(&common_sequences()),
+                true
+            )
+            .len()
+        );
+        let expected: Vec<char> = "X|".chars().collect();
+        assert_eq!(expected, password);
+    }
+
+    #[test]
+    fn test_matched_sequences() {
+        assert!(PasswordInfo::for_password("Tr0ub4dor&3")
+            .matched_sequences()
+            .is_empty());
+
+        let info = PasswordInfo::for_password("qwerty|asdf|fdsa|qwerty");
+        assert_eq!(&["asdf", "qwerty"], info.matched_sequences());
+
+        let info = PasswordInfoBuilder::new()
+            .detect_leet(true)
+            .with_extra_sequences(&["acme"])
+            .for_password("p@ssw0rd4cme");
+        assert_eq!(&["password", "acme"], info.matched_sequences());
+    }
+
+    #[test]
+    fn test_remove_empty_sequences() {
+        let mut password: Vec<char> = "ThisIsUntouched!".chars().collect();
+        assert_eq!(
+            0,
+            remove_common_sequences(&mut password, None, &SequenceSet::new(&[""]), false).len()
+        );
         let expected: Vec<char> = "ThisIsUntouched!".chars().collect();
         assert_eq!(expected, password);
+
+        let info = PasswordInfoBuilder::new()
+            .with_extra_sequences(&[""])
+            .for_password("password");
+        assert_eq!(0, info.length());
+    }
+
+    #[test]
+    fn test_remove_long_repetitions() {
+        let mut password: Vec<char> = "password".repeat(10_000).chars().collect();
+        assert_eq!(
+            10_000,
+            remove_common_sequences(
+                &mut password,
+                None,
+                &SequenceSet::new(&common_sequences()),
+                false
+            )
+            .len()
+        );
+        assert!(password.is_empty());
+
+        // Each removal joins a new `1234` around the removed one
+        let mut password: Vec<char> = format!("{}{}", "12".repeat(1000), "34".repeat(1000))
+            .chars()
+            .collect();
+        assert_eq!(
+            1000,
+            remove_common_sequences(
+                &mut password,
+                None,
+                &SequenceSet::new(&common_sequences()),
+                false
+            )
+            .len()
+        );
+        assert!(password.is_empty());
+
+        let password = "a".repeat(100_000);
+        assert_eq!(1, PasswordInfo::for_password(&password).length());
+    }
+
+    proptest! {
+        #[test]
+        fn test_stripping_never_grows(password in "\\PC*") {
+            let info = PasswordInfo::for_password(&password);
+            prop_assert!(info.length() <= info.original_length());
+            prop_assert_eq!(password.chars().count(), info.original_length());
+        }
+
+        #[test]
+        fn test_stripping_repeated_sequences(
+            parts in prop::collection::vec(prop::sample::select(common_sequences().into_owned()), 0..64),
+            separator in "\\PC?",
+        ) {
+            let password = parts.join(&separator);
+            let info = PasswordInfoBuilder::new().detect_leet(true).for_password(&password);
+            prop_assert!(info.length() <= info.original_length());
+        }
+
+        #[cfg(feature = "aho-corasick")]
+        #[test]
+        fn test_automaton_matches_naive(
+            parts in prop::collection::vec(
+                prop_oneof![
+                    prop::sample::select(common_sequences().into_owned()).prop_map(String::from),
+                    "[a-z0-9;]{0,3}",
+                ],
+                0..16,
+            ),
+        ) {
+            let sequences = SequenceSet::new(&common_sequences());
+            let mut automaton: Vec<char> = parts.concat().chars().collect();
+            let mut naive = automaton.clone();
+
+            let removed = remove_common_sequences(&mut automaton, None, &sequences, false);
+            let expected =
+                remove_common_sequences(&mut naive, None, &sequences.without_automaton(), false);
+            prop_assert_eq!(expected, removed);
+            prop_assert_eq!(naive, automaton);
+        }
+    }
+
+    #[test]
+    fn test_remove_chained_sequences() {
+        // Removing `1234` reveals `qwerty`, which is checked before `1234`
+        let mut password: Vec<char> = "qwe1234rty".chars().collect();
+        assert_eq!(
+            2,
+            remove_common_sequences(
+                &mut password,
+                None,
+                &SequenceSet::new(&common_sequences()),
+                false
+            )
+            .len()
+        );
+        assert!(password.is_empty());
+
+        let mut password: Vec<char> = "X|pass1234wordqwe000000rty|X".chars().collect();
+        assert_eq!(
+            4,
+            remove_common_sequences(
+                &mut password,
+                None,
+                &SequenceSet::new(&common_sequences()),
+                false
+            )
+            .len()
+        );
+        let expected: Vec<char> = "X||X".chars().collect();
+        assert_eq!(expected, password);
+    }
+
+    #[test]
+    fn test_remove_leet_sequences() {
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            // Leet sequences are untouched by default
+            let mut password: Vec<char> = "p@ssw0rd".chars().collect();
+            assert_eq!(
+                0,
+                remove_common_sequences(
+                    &mut password,
+                    None,
+                    &SequenceSet::new(&common_sequences()),
+                    false
+                )
+                .len()
+            );
+            let expected: Vec<char> = "p@ssw0rd".chars().collect();
+            assert_eq!(expected, password);
+        }
+
+        let mut password: Vec<char> = "p@ssw0rd".chars().collect();
+        assert_eq!(
+            1,
+            remove_common_sequences(
+                &mut password,
+                None,
+                &SequenceSet::new(&common_sequences()),
+                true
+            )
+            .len()
+        );
+        assert!(password.is_empty());
+
+        let mut password: Vec<char> = "X|passw0rd|$3nh@|1l0v3y0u".chars().collect();
+        assert_eq!(
+            3,
+            remove_common_sequences(
+                &mut password,
+                None,
+                &SequenceSet::new(&common_sequences()),
+                true
+            )
+            .len()
+        );
+        let expected: Vec<char> = "X|||".chars().collect();
+        assert_eq!(expected, password);
+
+        // `1` is a substitution for `l` and `i`
+        let mut password: Vec<char> = "p1cture1".chars().collect();
+        assert_eq!(
+            1,
+            remove_common_sequences(
+                &mut password,
+                None,
+                &SequenceSet::new(&common_sequences()),
+                true
+            )
+            .len()
+        );
+        assert!(password.is_empty());
+    }
+
+    #[test]
+    fn test_remove_case_insensitive_duplicates() {
+        let mut password: Vec<char> = "aAaAbB".chars().collect();
+        remove_repeating_characters(&mut password, None, false, 1);
+        let expected: Vec<char> = "aAaAbB".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "aAaAbB".chars().collect();
+        remove_repeating_characters(&mut password, None, true, 1);
+        let expected: Vec<char> = "ab".chars().collect();
+        assert_eq!(expected, password);
+
+        // `İ` lowercases to `i` followed by a combining dot, so it is not
+        // repeated by `i`
+        let mut password: Vec<char> = "İi\u{307}iI".chars().collect();
+        remove_repeating_characters(&mut password, None, true, 1);
+        let expected: Vec<char> = "İi\u{307}i".chars().collect();
+        assert_eq!(expected, password);
     }
 
     #[test]
     fn test_remove_duplicates() {
         let mut password: Vec<char> = "aabbccddeeff".chars().collect();
-        remove_repeating_characters(&mut password);
+        remove_repeating_characters(&mut password, None, false, 1);
         let expected: Vec<char> = "abcdef".chars().collect();
         assert_eq!(expected, password);
 
         let mut password: Vec<char> = "abba".chars().collect();
-        remove_repeating_characters(&mut password);
+        remove_repeating_characters(&mut password, None, false, 1);
         let expected: Vec<char> = "aba".chars().collect();
         assert_eq!(expected, password);
 
         let mut password: Vec<char> = "aabbbccccdddddeeeeeefffffff".chars().collect();
-        remove_repeating_characters(&mut password);
+        remove_repeating_characters(&mut password, None, false, 1);
         let expected: Vec<char> = "abcdef".chars().collect();
         assert_eq!(expected, password);
     }
 
+    #[test]
+    fn test_remove_duplicates_max_repeat() {
+        let mut password: Vec<char> = "aabbbccccd".chars().collect();
+        remove_repeating_characters(&mut password, None, false, 2);
+        let expected: Vec<char> = "aabbccd".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "aabbbccccd".chars().collect();
+        remove_repeating_characters(&mut password, None, false, 3);
+        let expected: Vec<char> = "aabbbcccd".chars().collect();
+        assert_eq!(expected, password);
+
+        // Zero is treated like one
+        let mut password: Vec<char> = "aabbbccccd".chars().collect();
+        remove_repeating_characters(&mut password, None, false, 0);
+        let expected: Vec<char> = "abcd".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "aAaAbB".chars().collect();
+        remove_repeating_characters(&mut password, None, true, 2);
+        let expected: Vec<char> = "aAbB".chars().collect();
+        assert_eq!(expected, password);
+    }
+
+    #[test]
+    fn test_remove_short_periods() {
+        // Period 1 is left to `remove_repeating_characters`
+        let mut password: Vec<char> = "xaaaaaay".chars().collect();
+        remove_short_periods(&mut password, None);
+        let expected: Vec<char> = "xaaaaaay".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "Xa1a1a1a1Y".chars().collect();
+        remove_short_periods(&mut password, None);
+        let expected: Vec<char> = "Xa1Y".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "Xab!ab!ab!Y".chars().collect();
+        remove_short_periods(&mut password, None);
+        let expected: Vec<char> = "Xab!Y".chars().collect();
+        assert_eq!(expected, password);
+
+        // An incomplete repetition at the end is kept
+        let mut password: Vec<char> = "a1a1a1a".chars().collect();
+        remove_short_periods(&mut password, None);
+        let expected: Vec<char> = "a1a".chars().collect();
+        assert_eq!(expected, password);
+
+        // Two repetitions are not enough
+        let mut password: Vec<char> = "Xa1a1Y".chars().collect();
+        remove_short_periods(&mut password, None);
+        let expected: Vec<char> = "Xa1a1Y".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = Vec::new();
+        remove_short_periods(&mut password, None);
+        assert!(password.is_empty());
+    }
+
+    #[test]
+    fn test_short_periods() {
+        // Period 1
+        assert_eq!(3, PasswordInfo::for_password("XaaaaaaY").length());
+        // Period 2
+        assert_eq!(4, PasswordInfo::for_password("Xa1a1a1a1Y").length());
+        assert_eq!(2, PasswordInfo::for_password("a1a1a1a1").length());
+        // Period 3
+        assert_eq!(5, PasswordInfo::for_password("Xab!ab!ab!Y").length());
+    }
+
+    #[test]
+    fn test_remove_repeating_patterns() {
+        let mut password: Vec<char> = "abcabcabc".chars().collect();
+        remove_repeating_patterns(&mut password, None);
+        let expected: Vec<char> = "abc".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "xyxyxy1".chars().collect();
+        remove_repeating_patterns(&mut password, None);
+        let expected: Vec<char> = "xy1".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "abcabcab".chars().collect();
+        remove_repeating_patterns(&mut password, None);
+        let expected: Vec<char> = "abc".chars().collect();
+        assert_eq!(expected, password);
+
+        // The repetition is not dominant
+        let mut password: Vec<char> = "abcdeabc".chars().collect();
+        remove_repeating_patterns(&mut password, None);
+        let expected: Vec<char> = "abcdeabc".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "xyxyThisIsUntouched".chars().collect();
+        remove_repeating_patterns(&mut password, None);
+        let expected: Vec<char> = "xyxyThisIsUntouched".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = Vec::new();
+        remove_repeating_patterns(&mut password, None);
+        assert!(password.is_empty());
+
+        // Long inputs are handled in linear time
+        let mut password: Vec<char> = "Secret1!".repeat(100_000).chars().collect();
+        remove_repeating_patterns(&mut password, None);
+        let expected: Vec<char> = "Secret1!".chars().collect();
+        assert_eq!(expected, password);
+    }
+
     #[test]
     fn test_remove_palindrome() {
         let mut password: Vec<char> = "abba".chars().collect();
-        remove_palindrome(&mut password);
+        remove_palindrome(&mut password, None, 0);
         let expected: Vec<char> = "ab".chars().collect();
         assert_eq!(expected, password);
 
         let mut password: Vec<char> = "Abcdedcba".chars().collect();
-        remove_palindrome(&mut password);
+        remove_palindrome(&mut password, None, 0);
         let expected: Vec<char> = "Abcde".chars().collect();
         assert_eq!(expected, password);
+
+        // The middle character of an odd palindrome is kept exactly once
+        let mut password: Vec<char> = "aba".chars().collect();
+        remove_palindrome(&mut password, None, 0);
+        let expected: Vec<char> = "ab".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "abXba".chars().collect();
+        remove_palindrome(&mut password, None, 0);
+        let expected: Vec<char> = "abX".chars().collect();
+        assert_eq!(expected, password);
+
+        // Near-palindromes are untouched
+        let mut password: Vec<char> = "abcXba".chars().collect();
+        remove_palindrome(&mut password, None, 0);
+        let expected: Vec<char> = "abcXba".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "abXYba".chars().collect();
+        remove_palindrome(&mut password, None, 0);
+        let expected: Vec<char> = "abXYba".chars().collect();
+        assert_eq!(expected, password);
+
+        // A single character is kept
+        let mut password: Vec<char> = "a".chars().collect();
+        remove_palindrome(&mut password, None, 0);
+        let expected: Vec<char> = "a".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = Vec::new();
+        remove_palindrome(&mut password, None, 0);
+        assert!(password.is_empty());
+    }
+
+    #[test]
+    fn test_remove_near_palindrome() {
+        let mut password: Vec<char> = "abccbx".chars().collect();
+        remove_palindrome(&mut password, None, 1);
+        let expected: Vec<char> = "abc".chars().collect();
+        assert_eq!(expected, password);
+
+        let mut password: Vec<char> = "abXYba".chars().collect();
+        remove_palindrome(&mut password, None, 1);
+        let expected: Vec<char> = "abX".chars().collect();
+        assert_eq!(expected, password);
+
+        // Too many mismatches
+        let mut password: Vec<char> = "abcxyz".chars().collect();
+        remove_palindrome(&mut password, None, 2);
+        let expected: Vec<char> = "abcxyz".chars().collect();
+        assert_eq!(expected, password);
+
+        // At least one pair must match
+        let mut password: Vec<char> = "ab".chars().collect();
+        remove_palindrome(&mut password, None, 1);
+        let expected: Vec<char> = "ab".chars().collect();
+        assert_eq!(expected, password);
+
+        // Exact palindromes are removed with any tolerance
+        let mut password: Vec<char> = "abcba".chars().collect();
+        remove_palindrome(&mut password, None, 3);
+        let expected: Vec<char> = "abc".chars().collect();
+        assert_eq!(expected, password);
     }
 }
@@ -0,0 +1,205 @@
+//! The entropy of passphrases that consist of dictionary words.
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{float, PasswordInfo, SEPARATOR_CHARS};
+
+/// The info about a passphrase like `correct horse battery staple` to
+/// calculate the passphrase's entropy.
+///
+/// The passphrase is split into words at every separator character and
+/// whitespace. A word that is part of the word list contributes
+/// `log_2(wordlist_len)` bits, because an attacker only needs to guess which
+/// word of the list was chosen. Every other word contributes the entropy of
+/// [`PasswordInfo::for_password`](PasswordInfo::for_password). The separators
+/// themselves do not contribute any entropy.
+///
+/// Repeated words are only scored once: consecutive identical words like
+/// `apple apple apple` count as one `apple`, and a group of words that is
+/// repeated to fill the whole passphrase like `a b a b a b` counts as one
+/// `a b`. Words are compared ignoring the case.
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::PasswordInfo;
+///
+/// let wordlist = ["correct", "horse", "battery", "staple"];
+/// let info = PasswordInfo::for_passphrase("correct horse battery staple", &wordlist);
+/// assert_eq!(4, info.dictionary_word_count());
+/// assert_eq!(8.0, info.get_entropy());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PassphraseInfo {
+    /// The amount of words of the passphrase.
+    word_count: usize,
+    /// The amount of words that are part of the word list.
+    dictionary_word_count: usize,
+    /// The amount of repeated words that do not contribute any entropy.
+    repeated_word_count: usize,
+    /// The length of the word list.
+    wordlist_len: usize,
+    /// The summed entropy of all words that are not part of the word list.
+    other_entropy: f64,
+}
+
+impl PassphraseInfo {
+    /// Calculates a new [`PassphraseInfo`](PassphraseInfo) for the given
+    /// passphrase and word list.
+    pub(crate) fn new(passphrase: &str, wordlist: &[&str]) -> Self {
+        let mut words: Vec<&str> = passphrase
+            .split(|c: char| c.is_whitespace() || SEPARATOR_CHARS.contains(c))
+            .filter(|word| !word.is_empty())
+            .collect();
+        let word_count = words.len();
+
+        remove_repeated_words(&mut words);
+
+        let mut dictionary_word_count = 0;
+        let mut other_entropy = 0.0;
+
+        for word in &words {
+            if wordlist.iter().any(|entry| eq_ignore_case(entry, word)) {
+                dictionary_word_count += 1;
+            } else {
+                other_entropy += PasswordInfo::for_password(word).get_entropy();
+            }
+        }
+
+        Self {
+            word_count,
+            dictionary_word_count,
+            repeated_word_count: word_count - words.len(),
+            wordlist_len: wordlist.len(),
+            other_entropy,
+        }
+    }
+
+    /// The amount of words of the passphrase.
+    #[must_use]
+    pub const fn word_count(&self) -> usize {
+        self.word_count
+    }
+
+    /// The amount of words of the passphrase that are part of the word list.
+    #[must_use]
+    pub const fn dictionary_word_count(&self) -> usize {
+        self.dictionary_word_count
+    }
+
+    /// The amount of repeated words of the passphrase that were only scored
+    /// once, like the last two words of `apple apple apple`.
+    #[must_use]
+    pub const fn repeated_word_count(&self) -> usize {
+        self.repeated_word_count
+    }
+
+    /// Calculates the entropy of the passphrase based on:
+    /// `dictionary_words * log_2(wordlist_len) + entropy_of_other_words`.
+    #[must_use]
+    pub fn get_entropy(&self) -> f64 {
+        if self.dictionary_word_count == 0 {
+            return self.other_entropy;
+        }
+
+        self.dictionary_word_count as f64 * float::log2(self.wordlist_len as f64)
+            + self.other_entropy
+    }
+}
+
+/// Removes repeated words from the passphrase in place.
+///
+/// First consecutive identical words are reduced to one word, then a group of
+/// words that is repeated to fill the whole passphrase is reduced to one group.
+fn remove_repeated_words(words: &mut Vec<&str>) {
+    words.dedup_by(|a, b| eq_ignore_case(a, b));
+
+    let len = words.len();
+    let period = (1..=(len / 2))
+        .filter(|&period| len % period == 0)
+        .find(|&period| {
+            words
+                .iter()
+                .zip(&words[period..])
+                .all(|(a, b)| eq_ignore_case(a, b))
+        });
+
+    if let Some(period) = period {
+        words.truncate(period);
+    }
+}
+
+/// True, if both words are equal ignoring the case.
+fn eq_ignore_case(a: &str, b: &str) -> bool {
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .eq(b.chars().flat_map(char::to_lowercase))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PasswordInfo;
+
+    const ERROR_MARGIN: f64 = f64::EPSILON;
+
+    #[test]
+    fn test_for_passphrase() {
+        let wordlist = ["correct", "horse", "battery", "staple"];
+
+        let info = PasswordInfo::for_passphrase("correct horse battery staple", &wordlist);
+        assert_eq!(4, info.word_count());
+        assert_eq!(4, info.dictionary_word_count());
+        assert!((8.0 - info.get_entropy()).abs() < ERROR_MARGIN);
+
+        // The case of the words and multiple separators are ignored
+        let info = PasswordInfo::for_passphrase("  Correct--HORSE__battery ", &wordlist);
+        assert_eq!(3, info.word_count());
+        assert_eq!(3, info.dictionary_word_count());
+        assert!((6.0 - info.get_entropy()).abs() < ERROR_MARGIN);
+
+        // Words that are not part of the list are scored by character
+        let info = PasswordInfo::for_passphrase("correct letmein", &wordlist);
+        let expected = 2.0 + PasswordInfo::for_password("letmein").get_entropy();
+        assert_eq!(2, info.word_count());
+        assert_eq!(1, info.dictionary_word_count());
+        assert!((expected - info.get_entropy()).abs() < ERROR_MARGIN);
+
+        // Empty word list and passphrase
+        let info = PasswordInfo::for_passphrase("letmein", &[]);
+        let expected = PasswordInfo::for_password("letmein").get_entropy();
+        assert!((expected - info.get_entropy()).abs() < ERROR_MARGIN);
+
+        let info = PasswordInfo::for_passphrase("", &wordlist);
+        assert_eq!(0, info.word_count());
+        assert!(info.get_entropy().abs() < ERROR_MARGIN);
+    }
+
+    #[test]
+    fn test_repeated_words() {
+        let wordlist = ["apple", "word", "a", "b", "c"];
+
+        for (passphrase, unique) in &[
+            ("word word word", "word"),
+            ("apple Apple APPLE", "apple"),
+            ("a b a b a b", "a b"),
+            ("a b c a b c", "a b c"),
+            ("word word a word a", "word a"),
+        ] {
+            let info = PasswordInfo::for_passphrase(passphrase, &wordlist);
+            let expected = PasswordInfo::for_passphrase(unique, &wordlist);
+            assert_eq!(
+                info.word_count() - expected.word_count(),
+                info.repeated_word_count()
+            );
+            assert!((expected.get_entropy() - info.get_entropy()).abs() < ERROR_MARGIN);
+        }
+
+        // Only a repetition that fills the whole passphrase is removed
+        let info = PasswordInfo::for_passphrase("a b a b a", &wordlist);
+        assert_eq!(0, info.repeated_word_count());
+        assert_eq!(5, info.dictionary_word_count());
+    }
+}
@@ -0,0 +1,133 @@
+//! The entropy of passwords generated from a known pattern.
+
+use alloc::vec::Vec;
+
+use crate::{log_power, DIGIT_CHARS};
+
+/// A component of a password pattern, e.g. three words of a dictionary.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Component {
+    /// Words that are chosen randomly from a dictionary.
+    Words {
+        /// The amount of words.
+        count: usize,
+        /// The amount of words in the dictionary.
+        dict_size: usize,
+    },
+    /// The given amount of random digits.
+    Digits(usize),
+    /// The given amount of random symbols, chosen from a pool of the given
+    /// size.
+    Symbols(usize, usize),
+}
+
+impl Component {
+    /// The entropy of the component in bits, which is
+    /// `count * log_2(pool)`. A pool of less than two choices contributes
+    /// nothing.
+    #[must_use]
+    pub fn entropy(&self) -> f64 {
+        let (count, pool) = match *self {
+            Self::Words { count, dict_size } => (count, dict_size),
+            Self::Digits(count) => (count, DIGIT_CHARS.len()),
+            Self::Symbols(count, pool) => (count, pool),
+        };
+
+        if pool < 2 {
+            return 0.0;
+        }
+
+        log_power(pool as f64, count, 2.0)
+    }
+}
+
+/// The entropy of the process that generates a password from a pattern, like
+/// three words followed by two digits and a symbol.
+///
+/// Unlike [`PasswordInfo`](crate::PasswordInfo), which describes a single
+/// password, this calculates the entropy of all passwords a generator could
+/// create. It is the sum of the entropies of all components, so it assumes
+/// each component is chosen randomly and independently.
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::{Component, PatternEntropy};
+///
+/// let pattern = PatternEntropy::new()
+///     .with(Component::Words { count: 3, dict_size: 7776 })
+///     .with(Component::Digits(2))
+///     .with(Component::Symbols(1, 32));
+/// assert!(pattern.entropy() > 50.0);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PatternEntropy {
+    /// The components of the pattern.
+    components: Vec<Component>,
+}
+
+impl PatternEntropy {
+    /// Creates a new pattern without any components.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given component to the pattern.
+    #[must_use]
+    pub fn with(mut self, component: Component) -> Self {
+        self.components.push(component);
+        self
+    }
+
+    /// The components of the pattern.
+    #[must_use]
+    pub fn components(&self) -> &[Component] {
+        &self.components
+    }
+
+    /// The entropy of the pattern in bits, which is the sum of the entropies
+    /// of all components.
+    #[must_use]
+    pub fn entropy(&self) -> f64 {
+        self.components.iter().map(Component::entropy).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Component, PatternEntropy};
+
+    const ERROR_MARGIN: f64 = 1e-9;
+
+    #[test]
+    fn test_component_entropy() {
+        let words = Component::Words {
+            count: 3,
+            dict_size: 7776,
+        };
+        assert!((3.0 * 7776.0_f64.log2() - words.entropy()).abs() < ERROR_MARGIN);
+        assert!((2.0 * 10.0_f64.log2() - Component::Digits(2).entropy()).abs() < ERROR_MARGIN);
+        assert!((5.0 - Component::Symbols(1, 32).entropy()).abs() < ERROR_MARGIN);
+
+        assert!(Component::Digits(0).entropy().abs() < ERROR_MARGIN);
+        assert!(Component::Symbols(4, 1).entropy().abs() < ERROR_MARGIN);
+        assert!(Component::Symbols(4, 0).entropy().abs() < ERROR_MARGIN);
+    }
+
+    #[test]
+    fn test_pattern_entropy() {
+        assert!(PatternEntropy::new().entropy().abs() < ERROR_MARGIN);
+
+        let pattern = PatternEntropy::new()
+            .with(Component::Words {
+                count: 3,
+                dict_size: 7776,
+            })
+            .with(Component::Digits(2))
+            .with(Component::Symbols(1, 32));
+        let expected = 3.0 * 7776.0_f64.log2() + 2.0 * 10.0_f64.log2() + 5.0;
+
+        assert_eq!(3, pattern.components().len());
+        assert!((expected - pattern.entropy()).abs() < ERROR_MARGIN);
+    }
+}
@@ -0,0 +1,18 @@
+//! The penalty for common sequences in a password.
+
+/// How common sequences like `password` or `qwerty` are penalized.
+///
+/// The default is [`Remove`](Penalty::Remove).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Penalty {
+    /// The common sequences are removed from the password and do not
+    /// contribute to the entropy at all. So `password` and
+    /// `passwordpassword` both have an entropy of `0.0`.
+    #[default]
+    Remove,
+    /// The common sequences are removed from the password, but each removed
+    /// sequence contributes `log_2(n)` bits to the entropy, where `n` is the
+    /// amount of distinct non-empty common sequences. This is the entropy of
+    /// guessing the sequence from the list of common sequences.
+    FixedBits,
+}
@@ -0,0 +1,146 @@
+//! The entropy of numeric PINs.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{float, PasswordError};
+
+/// Common PINs that are not caught by the structural rules of
+/// [`PinInfo`](PinInfo), like years or keypad patterns.
+static COMMON_PINS: &[&str] = &[
+    "1004", "2000", "2001", "1010", "1122", "1313", "1230", "0852", "2580", "112233", "159753",
+    "123123", "147258", "147852", "789456",
+];
+
+/// The info about a numeric PIN like `4917` to calculate the PIN's entropy
+/// against an attacker that knows it is a PIN.
+///
+/// Each digit contributes `log_2(10)` bits. A common PIN is guessed first, so
+/// it has no entropy at all. A PIN is common, if it
+/// - consists of a single repeated digit like `0000` or `1111`,
+/// - repeats a pair of digits like `1212` or `696969`,
+/// - is an ascending or descending run like `1234` or `4321`, or
+/// - is part of a short list of other common PINs like `2580` or `1004`.
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::{PasswordError, PasswordInfo};
+///
+/// let info = PasswordInfo::pin_entropy("4917")?;
+/// assert!(!info.is_common_pin());
+///
+/// let info = PasswordInfo::pin_entropy("1234")?;
+/// assert!(info.is_common_pin());
+/// assert_eq!(0.0, info.get_entropy());
+///
+/// assert_eq!(Err(PasswordError::NotNumeric), PasswordInfo::pin_entropy("12a4"));
+/// # Ok::<(), PasswordError>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PinInfo {
+    /// The amount of digits of the PIN.
+    length: usize,
+    /// The PIN is a common PIN.
+    is_common_pin: bool,
+}
+
+impl PinInfo {
+    /// Calculates a new [`PinInfo`](PinInfo) for the given PIN.
+    pub(crate) fn new(pin: &str) -> Result<Self, PasswordError> {
+        if pin.is_empty() {
+            return Err(PasswordError::Empty);
+        }
+        if !pin.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(PasswordError::NotNumeric);
+        }
+
+        Ok(Self {
+            length: pin.len(),
+            is_common_pin: is_common_pin(pin.as_bytes()),
+        })
+    }
+
+    /// The amount of digits of the PIN.
+    #[must_use]
+    pub const fn length(&self) -> usize {
+        self.length
+    }
+
+    /// True, if the PIN is a common PIN, see [`PinInfo`](PinInfo).
+    #[must_use]
+    pub const fn is_common_pin(&self) -> bool {
+        self.is_common_pin
+    }
+
+    /// Calculates the entropy of the PIN based on: `log_2(10 ^ length)`, or
+    /// `0.0` for a common PIN.
+    #[must_use]
+    pub fn get_entropy(&self) -> f64 {
+        if self.is_common_pin {
+            return 0.0;
+        }
+
+        self.length as f64 * float::log2(10.0)
+    }
+}
+
+/// True, if the given digits are a common PIN, see [`PinInfo`](PinInfo).
+fn is_common_pin(digits: &[u8]) -> bool {
+    let repeats = |period: usize| {
+        digits.len() >= 2 * period && digits.iter().zip(&digits[period..]).all(|(a, b)| a == b)
+    };
+    let steps = |step: i8| {
+        digits
+            .windows(2)
+            .all(|pair| pair[1] as i8 - pair[0] as i8 == step)
+    };
+
+    repeats(1)
+        || repeats(2)
+        || (digits.len() >= 3 && (steps(1) || steps(-1)))
+        || COMMON_PINS.iter().any(|pin| pin.as_bytes() == digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PasswordError, PasswordInfo};
+
+    const ERROR_MARGIN: f64 = f64::EPSILON;
+
+    #[test]
+    fn test_pin_entropy() {
+        let info = PasswordInfo::pin_entropy("4917").expect("PIN is numeric");
+        assert_eq!(4, info.length());
+        assert!(!info.is_common_pin());
+        assert!((4.0 * 10.0_f64.log2() - info.get_entropy()).abs() < ERROR_MARGIN);
+
+        assert_eq!(Err(PasswordError::Empty), PasswordInfo::pin_entropy(""));
+        assert_eq!(
+            Err(PasswordError::NotNumeric),
+            PasswordInfo::pin_entropy("12a4")
+        );
+        // Only ASCII digits are digits of a PIN
+        assert_eq!(
+            Err(PasswordError::NotNumeric),
+            PasswordInfo::pin_entropy("١٢٣٤")
+        );
+    }
+
+    #[test]
+    fn test_common_pins() {
+        for pin in &[
+            "0000", "1111", "999999", "1212", "6969", "696969", "1234", "4321", "123456",
+            "3456789", "987654", "2580", "1004",
+        ] {
+            let info = PasswordInfo::pin_entropy(pin).expect("PIN is numeric");
+            assert!(info.is_common_pin(), "{}", pin);
+            assert!(info.get_entropy().abs() < ERROR_MARGIN);
+        }
+
+        for pin in &["1", "12", "4917", "1213", "1235", "9012", "121", "0852741"] {
+            let info = PasswordInfo::pin_entropy(pin).expect("PIN is numeric");
+            assert!(!info.is_common_pin(), "{}", pin);
+        }
+    }
+}
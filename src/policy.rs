@@ -0,0 +1,358 @@
+//! The password policies a password can be validated against.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::{GroupKind, PasswordInfoBuilder};
+
+/// A password policy that bundles the requirements a password must meet.
+///
+/// With the feature `serde`, a policy can be loaded from a configuration file.
+/// Missing fields use their default, which accepts every password.
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::{GroupKind, PasswordInfo, Policy};
+///
+/// let policy = Policy {
+///     min_length: 8,
+///     min_bits: 50.0,
+///     required_groups: vec![GroupKind::Lower, GroupKind::Digit],
+///     forbidden_sequences: vec!["acme".into()],
+/// };
+/// assert!(!PasswordInfo::for_password("letmein").is_acceptable(&policy));
+/// assert!(PasswordInfo::for_password("correct horse battery 42").is_acceptable(&policy));
+/// assert!(!policy.evaluate("correct horse acme battery 42").passed);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+pub struct Policy {
+    /// The minimum stripped length of the password.
+    pub min_length: usize,
+    /// The minimum entropy of the password in bits.
+    pub min_bits: f64,
+    /// The character groups the password must contain at least one character
+    /// of.
+    pub required_groups: Vec<GroupKind>,
+    /// The sequences the password must not contain, like the name of the
+    /// company. The case is ignored, so `acme` also forbids `ACME`. See
+    /// [`evaluate`](Policy::evaluate).
+    pub forbidden_sequences: Vec<String>,
+}
+
+impl Policy {
+    /// Evaluates the given password against this policy.
+    ///
+    /// The [`forbidden sequences`](Policy::forbidden_sequences) are reported
+    /// as a violation and are also added to the common sequences, so they are
+    /// removed from the password like `qwerty`.
+    #[must_use]
+    pub fn evaluate(&self, password: &str) -> PolicyResult {
+        let sequences: Vec<&str> = self
+            .forbidden_sequences
+            .iter()
+            .map(String::as_str)
+            .collect();
+
+        PasswordInfoBuilder::new()
+            .with_extra_sequences(&sequences)
+            .for_password(password)
+            .evaluate(self)
+    }
+}
+
+/// The result of the evaluation of a password against a [`Policy`](Policy).
+///
+/// Each violation contains everything to render a precise message, like the
+/// actual and the required length.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PolicyResult {
+    /// The password meets all rules of the policy.
+    pub passed: bool,
+    /// The rules the password violates, in the order of the fields of the
+    /// policy, followed by the violations of the builder configuration.
+    pub violations: Vec<PolicyViolation>,
+}
+
+/// A rule of a [`Policy`](Policy) a password violates.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PolicyViolation {
+    /// The stripped length is below the
+    /// [`minimum length`](Policy::min_length).
+    TooShort {
+        /// The stripped length of the password.
+        got: usize,
+        /// The minimum length of the policy.
+        required: usize,
+    },
+    /// The entropy is below the [`minimum entropy`](Policy::min_bits).
+    EntropyTooLow {
+        /// The entropy of the password in bits.
+        got: f64,
+        /// The minimum entropy of the policy in bits.
+        required: f64,
+    },
+    /// The password does not contain a character of the
+    /// [`required group`](Policy::required_groups).
+    MissingGroup(GroupKind),
+    /// The password contains the
+    /// [`forbidden sequence`](Policy::forbidden_sequences).
+    ContainsSequence(String),
+    /// The base is below the
+    /// [`min_meaningful_base`](crate::PasswordInfo::min_meaningful_base), so
+    /// the password is never acceptable.
+    TinyAlphabet {
+        /// The base of the password.
+        got: u16,
+        /// The minimum meaningful base of the builder.
+        required: u16,
+    },
+    /// The password is
+    /// [`whitespace only`](crate::PasswordInfo::is_whitespace_only) and the
+    /// builder rated it as
+    /// [`weak`](crate::PasswordInfoBuilder::weak_whitespace_only).
+    WhitespaceOnly,
+}
+
+impl PolicyResult {
+    /// Creates a new result of the given violations.
+    pub(crate) fn new(violations: Vec<PolicyViolation>) -> Self {
+        Self {
+            passed: violations.is_empty(),
+            violations,
+        }
+    }
+}
+
+/// The lowercase password before stripping, to find the
+/// [`forbidden sequences`](Policy::forbidden_sequences) of a policy in it.
+///
+/// The [`Debug`](fmt::Debug) output does not contain the password.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize))]
+pub struct LowercasePassword(String);
+
+impl LowercasePassword {
+    /// Lowercases the given characters of a password.
+    pub fn new(password: &[char]) -> Self {
+        Self(password.iter().flat_map(|c| c.to_lowercase()).collect())
+    }
+
+    /// Appends the lowercase of the given character.
+    pub fn push(&mut self, c: char) {
+        self.0.extend(c.to_lowercase());
+    }
+
+    /// True, if the password contains the given sequence, ignoring the case.
+    /// The empty sequence is never contained.
+    pub fn contains(&self, sequence: &str) -> bool {
+        !sequence.is_empty() && self.0.contains(&sequence.to_lowercase())
+    }
+}
+
+impl fmt::Debug for LowercasePassword {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LowercasePassword(..)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GroupKind, PasswordInfo, PasswordInfoBuilder, Policy, PolicyViolation};
+
+    #[test]
+    fn test_is_acceptable() {
+        // The default policy accepts every password
+        assert!(PasswordInfo::for_password("").is_acceptable(&Policy::default()));
+
+        let policy = Policy {
+            min_length: 8,
+            ..Policy::default()
+        };
+        assert!(!PasswordInfo::for_password("letmein").is_acceptable(&policy));
+        #[cfg(not(feature = "large-wordlist"))]
+        assert!(PasswordInfo::for_password("letmein!").is_acceptable(&policy));
+        // The stripped length is checked
+        assert!(!PasswordInfo::for_password("password").is_acceptable(&policy));
+
+        let policy = Policy {
+            min_bits: 40.0,
+            ..Policy::default()
+        };
+        assert!(!PasswordInfo::for_password("letmein").is_acceptable(&policy));
+        #[cfg(not(feature = "large-wordlist"))]
+        assert!(PasswordInfo::for_password("letmein2").is_acceptable(&policy));
+
+        let policy = Policy {
+            required_groups: vec![GroupKind::Upper, GroupKind::Digit],
+            ..Policy::default()
+        };
+        assert!(!PasswordInfo::for_password("letmein2").is_acceptable(&policy));
+        assert!(PasswordInfo::for_password("LetMeIn2").is_acceptable(&policy));
+    }
+
+    #[test]
+    fn test_missing_required_groups() {
+        let info = PasswordInfo::for_password("letmein");
+        assert!(info.missing_required_groups(&Policy::default()).is_empty());
+
+        let policy = Policy {
+            required_groups: vec![
+                GroupKind::Digit,
+                GroupKind::Lower,
+                GroupKind::Upper,
+                GroupKind::Digit,
+            ],
+            ..Policy::default()
+        };
+        #[cfg(not(feature = "large-wordlist"))]
+        assert_eq!(
+            vec![GroupKind::Digit, GroupKind::Upper],
+            info.missing_required_groups(&policy)
+        );
+        assert!(PasswordInfo::for_password("LetMeIn2")
+            .missing_required_groups(&policy)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let policy = Policy {
+            min_length: 8,
+            min_bits: 40.0,
+            required_groups: vec![GroupKind::Upper, GroupKind::Digit],
+            forbidden_sequences: vec!["acme".into()],
+        };
+
+        let info = PasswordInfo::for_password("letmein");
+        let result = info.evaluate(&policy);
+        assert!(!result.passed);
+        #[cfg(not(feature = "large-wordlist"))]
+        assert_eq!(
+            vec![
+                PolicyViolation::TooShort {
+                    got: 7,
+                    required: 8
+                },
+                PolicyViolation::EntropyTooLow {
+                    got: info.get_entropy(),
+                    required: 40.0
+                },
+                PolicyViolation::MissingGroup(GroupKind::Upper),
+                PolicyViolation::MissingGroup(GroupKind::Digit),
+            ],
+            result.violations
+        );
+
+        let result = policy.evaluate("Tr0ub4dor&3");
+        assert!(result.passed);
+        assert!(result.violations.is_empty());
+
+        let result = policy.evaluate("Tr0ub4dor&3acme");
+        assert!(!result.passed);
+        assert_eq!(
+            vec![PolicyViolation::ContainsSequence("acme".into())],
+            result.violations
+        );
+
+        // The forbidden sequences are found in the original password,
+        // ignoring the case
+        for password in &["Tr0ub4dor&3acme", "Tr0ub4dor&3ACME", "xyzAcMe42"] {
+            assert!(!policy.evaluate(password).passed, "{}", password);
+            let info = PasswordInfo::for_password(password);
+            assert!(!info.evaluate(&policy).passed, "{}", password);
+            assert!(!info.is_acceptable(&policy), "{}", password);
+        }
+        let info = PasswordInfoBuilder::new()
+            .with_extra_sequences(&["acme"])
+            .for_password("Tr0ub4dor&3acme");
+        assert!(!info.evaluate(&policy).passed);
+
+        // An empty forbidden sequence forbids nothing
+        let policy = Policy {
+            forbidden_sequences: vec![String::new()],
+            ..Policy::default()
+        };
+        assert!(policy.evaluate("Tr0ub4dor&3").passed);
+    }
+
+    #[test]
+    fn test_evaluate_matches_is_acceptable() {
+        let policy = Policy {
+            min_length: 1,
+            min_bits: 1.0,
+            ..Policy::default()
+        };
+        let info = PasswordInfoBuilder::new()
+            .min_meaningful_base(10)
+            .for_password("*@*@*@*@");
+        let result = info.evaluate(&policy);
+        assert!(!info.is_acceptable(&policy));
+        assert!(!result.passed);
+        assert_eq!(
+            vec![PolicyViolation::TinyAlphabet {
+                got: 5,
+                required: 10
+            }],
+            result.violations
+        );
+
+        let info = PasswordInfoBuilder::new()
+            .weak_whitespace_only(true)
+            .for_password("  _  ");
+        assert!(!info.is_acceptable(&Policy::default()));
+        assert_eq!(
+            vec![PolicyViolation::WhitespaceOnly],
+            info.evaluate(&Policy::default()).violations
+        );
+
+        let policies = [
+            Policy::default(),
+            policy,
+            Policy {
+                min_length: 8,
+                min_bits: 40.0,
+                required_groups: vec![GroupKind::Upper, GroupKind::Digit],
+                forbidden_sequences: vec!["acme".into()],
+            },
+        ];
+        for password in &["", "password", "*@*@*@*@", "Tr0ub4dor&3", "Tr0ub4dor&3Acme"] {
+            let info = PasswordInfoBuilder::new()
+                .min_meaningful_base(10)
+                .for_password(password);
+            for policy in &policies {
+                assert_eq!(
+                    info.is_acceptable(policy),
+                    info.evaluate(policy).passed,
+                    "{password}"
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let json = r#"{"min_length": 10, "required_groups": ["Lower", "Digit"]}"#;
+        let policy: Policy = serde_json::from_str(json).expect("deserialization failed");
+        assert_eq!(
+            Policy {
+                min_length: 10,
+                required_groups: vec![GroupKind::Lower, GroupKind::Digit],
+                ..Policy::default()
+            },
+            policy
+        );
+
+        let json = serde_json::to_string(&policy).expect("serialization failed");
+        let deserialized: Policy = serde_json::from_str(&json).expect("deserialization failed");
+        assert_eq!(policy, deserialized);
+    }
+}
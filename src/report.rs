@@ -0,0 +1,99 @@
+//! A summary of all metrics of a password.
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::PasswordStrength;
+
+/// A summary of all metrics of a password, created by
+/// [`PasswordInfo::report`](crate::PasswordInfo::report).
+///
+/// It is meant to be returned as a single serializable value, e.g. in the
+/// response of an API.
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::{PasswordInfo, PasswordStrength};
+///
+/// let report = PasswordInfo::for_password("letmein").report();
+/// # #[cfg(not(feature = "large-wordlist"))]
+/// assert_eq!(PasswordStrength::Weak, report.strength);
+/// assert!(!report.suggestions.is_empty());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PasswordReport {
+    /// The entropy of the password in bits.
+    pub entropy: f64,
+    /// The stripped length of the password.
+    pub length: usize,
+    /// The calculated base of the password.
+    pub base: u16,
+    /// The classification of the entropy.
+    pub strength: PasswordStrength,
+    /// The password contains at least one replace character.
+    pub has_replace: bool,
+    /// The password contains at least one separator character.
+    pub has_separator: bool,
+    /// The password contains at least one other special character.
+    pub has_other_special: bool,
+    /// The password contains at least one lower character.
+    pub has_lower: bool,
+    /// The password contains at least one upper character.
+    pub has_upper: bool,
+    /// The password contains at least one digit.
+    pub has_digit: bool,
+    /// The password contains at least one letter beyond ASCII.
+    pub has_unicode_letter: bool,
+    /// The password contains at least one emoji.
+    pub has_emoji: bool,
+    /// The password contains at least one printable ASCII character that is
+    /// not part of any group.
+    pub has_unclassified: bool,
+    /// The bytes contain at least one byte beyond ASCII.
+    pub has_extended_byte: bool,
+    /// Human-readable tips to improve the password.
+    pub suggestions: Vec<&'static str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PasswordInfo, PasswordStrength};
+
+    #[test]
+    fn test_report() {
+        let info = PasswordInfo::for_password("Tr0ub4dor&3");
+        let report = info.report();
+
+        assert!((info.get_entropy() - report.entropy).abs() < f64::EPSILON);
+        assert_eq!(info.length(), report.length);
+        assert_eq!(info.base(), report.base);
+        assert_eq!(PasswordStrength::Strong, report.strength);
+        assert!(report.has_replace);
+        assert!(!report.has_separator);
+        assert!(!report.has_other_special);
+        assert!(report.has_lower);
+        assert!(report.has_upper);
+        assert!(report.has_digit);
+        assert!(!report.has_unicode_letter);
+        assert_eq!(info.suggestions(), report.suggestions);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize() {
+        let report = PasswordInfo::for_password("letmein").report();
+        let json = serde_json::to_value(&report).expect("serialization failed");
+
+        #[cfg(not(feature = "large-wordlist"))]
+        {
+            assert_eq!(Some(7), json["length"].as_u64());
+            assert_eq!(Some("Weak"), json["strength"].as_str());
+            assert_eq!(Some(true), json["has_lower"].as_bool());
+        }
+        assert!(json["suggestions"].is_array());
+    }
+}
@@ -0,0 +1,63 @@
+//! The base contribution of the Unicode scripts of the letters beyond ASCII,
+//! see [`PasswordInfoBuilder::script_aware_base`](crate::PasswordInfoBuilder::script_aware_base).
+
+use unicode_script::{Script, UnicodeScript};
+
+use crate::CharsetConfig;
+
+/// The script of the given character, or `None` if it is not a letter or is
+/// shared by several scripts, like digits and punctuation.
+pub fn script_of(c: char) -> Option<Script> {
+    if !c.is_alphabetic() {
+        return None;
+    }
+
+    match c.script() {
+        Script::Common | Script::Inherited | Script::Unknown => None,
+        script => Some(script),
+    }
+}
+
+/// The base contribution of the letters beyond ASCII of the given script.
+///
+/// The small alphabets count all their lowercase and uppercase letters. The
+/// letters of all other scripts, including the Latin letters beyond ASCII,
+/// count as the
+/// [`unicode letter base`](crate::CharsetConfig::unicode_letter_base) of the
+/// charset.
+pub fn script_base(script: Script, charset: &CharsetConfig<'_>) -> usize {
+    match script {
+        Script::Cyrillic => 66,
+        Script::Greek => 48,
+        Script::Armenian => 76,
+        Script::Georgian => 33,
+        Script::Hebrew => 27,
+        Script::Arabic => 28,
+        Script::Thai => 44,
+        _ => usize::from(charset.unicode_letter_base),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use unicode_script::Script;
+
+    use crate::{
+        script::{script_base, script_of},
+        CharsetConfig,
+    };
+
+    #[test]
+    fn test_script_of() {
+        assert_eq!(Some(Script::Latin), script_of('a'));
+        assert_eq!(Some(Script::Latin), script_of('é'));
+        assert_eq!(Some(Script::Cyrillic), script_of('р'));
+        assert_eq!(None, script_of('1'));
+        assert_eq!(None, script_of('-'));
+
+        let charset = CharsetConfig::default();
+        assert_eq!(66, script_base(Script::Cyrillic, &charset));
+        assert_eq!(62, script_base(Script::Latin, &charset));
+        assert_eq!(62, script_base(Script::Han, &charset));
+    }
+}
@@ -0,0 +1,151 @@
+//! The prepared common sequences that are removed from a password.
+
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+#[cfg(feature = "aho-corasick")]
+use aho_corasick::AhoCorasick;
+
+/// The characters of the common sequences, prepared once to be removed from
+/// many passwords.
+///
+/// With the feature `aho-corasick`, an Aho-Corasick automaton of all
+/// sequences and their reverses finds the sequences that occur in a password
+/// in a single pass. Only these candidates are then matched one by one, so the
+/// removal is exactly the same as without the automaton.
+#[derive(Clone, Debug)]
+pub struct SequenceSet {
+    /// The characters of each sequence.
+    chars: Vec<Vec<char>>,
+    /// The amount of distinct non-empty sequences.
+    distinct_count: usize,
+    /// The automaton of all non-empty sequences, each followed by its
+    /// reverse, and the index of the sequence of each pair of patterns.
+    #[cfg(feature = "aho-corasick")]
+    automaton: Option<(AhoCorasick, Vec<usize>)>,
+}
+
+impl SequenceSet {
+    /// Collects the characters of each of the given sequences.
+    pub fn new(sequences: &[&str]) -> Self {
+        let mut distinct: Vec<&str> = sequences
+            .iter()
+            .copied()
+            .filter(|sequence| !sequence.is_empty())
+            .collect();
+        distinct.sort_unstable();
+        distinct.dedup();
+
+        Self {
+            chars: sequences.iter().map(|s| s.chars().collect()).collect(),
+            distinct_count: distinct.len(),
+            #[cfg(feature = "aho-corasick")]
+            automaton: Self::build_automaton(sequences),
+        }
+    }
+
+    /// The amount of distinct non-empty sequences, which is the amount of
+    /// sequences an attacker has to try.
+    pub const fn distinct_count(&self) -> usize {
+        self.distinct_count
+    }
+
+    /// Builds the automaton of all non-empty sequences and their reverses.
+    #[cfg(feature = "aho-corasick")]
+    fn build_automaton(sequences: &[&str]) -> Option<(AhoCorasick, Vec<usize>)> {
+        use alloc::string::String;
+
+        let mut patterns: Vec<String> = Vec::new();
+        let mut indices = Vec::new();
+        for (index, sequence) in sequences.iter().enumerate() {
+            if !sequence.is_empty() {
+                patterns.push(String::from(*sequence));
+                patterns.push(sequence.chars().rev().collect());
+                indices.push(index);
+            }
+        }
+
+        AhoCorasick::new(&patterns)
+            .ok()
+            .map(|automaton| (automaton, indices))
+    }
+
+    /// The sequences that occur in the password forwards or reversed, or
+    /// `None` if every sequence must be matched.
+    ///
+    /// The automaton only matches the exact characters, so without it or with
+    /// `detect_leet` every sequence is a candidate.
+    #[cfg_attr(
+        not(feature = "aho-corasick"),
+        allow(clippy::unused_self, clippy::missing_const_for_fn)
+    )]
+    pub fn candidates(&self, password: &[char], detect_leet: bool) -> Option<Vec<bool>> {
+        #[cfg(feature = "aho-corasick")]
+        if let (Some((automaton, indices)), false) = (&self.automaton, detect_leet) {
+            #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+            let mut haystack: alloc::string::String = password.iter().collect();
+
+            let mut candidates = alloc::vec![false; self.chars.len()];
+            for found in automaton.find_overlapping_iter(&haystack) {
+                candidates[indices[found.pattern().as_usize() / 2]] = true;
+            }
+
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut haystack);
+
+            return Some(candidates);
+        }
+
+        #[cfg(not(feature = "aho-corasick"))]
+        let _ = (password, detect_leet);
+
+        None
+    }
+
+    /// Drops the automaton, so every sequence is a candidate.
+    #[cfg(all(test, feature = "aho-corasick"))]
+    pub fn without_automaton(mut self) -> Self {
+        self.automaton = None;
+        self
+    }
+}
+
+impl Deref for SequenceSet {
+    type Target = [Vec<char>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.chars
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sequence::SequenceSet;
+
+    #[test]
+    fn test_sequence_set() {
+        let sequences = SequenceSet::new(&["asdf", "", "qwerty"]);
+        assert_eq!(3, sequences.len());
+        assert!(sequences[1].is_empty());
+        assert_eq!(&['q', 'w', 'e', 'r', 't', 'y'], &*sequences[2]);
+        assert_eq!(2, sequences.distinct_count());
+
+        let sequences = SequenceSet::new(&["asdf", "", "asdf", "qwerty", ""]);
+        assert_eq!(5, sequences.len());
+        assert_eq!(2, sequences.distinct_count());
+        assert_eq!(0, SequenceSet::new(&[""]).distinct_count());
+    }
+
+    #[cfg(feature = "aho-corasick")]
+    #[test]
+    fn test_candidates() {
+        let sequences = SequenceSet::new(&["asdf", "", "qwerty", "fd"]);
+        let password: Vec<char> = "xfdsax".chars().collect();
+
+        let candidates = sequences.candidates(&password, false);
+        assert_eq!(Some(vec![true, false, false, true]), candidates);
+
+        // Leet substitutions are not part of the automaton
+        assert_eq!(None, sequences.candidates(&password, true));
+    }
+}
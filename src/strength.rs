@@ -0,0 +1,239 @@
+//! The classification of the strength of a password.
+
+use alloc::{borrow::Cow, vec::Vec};
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::PasswordError;
+
+/// The thresholds of the [`default`](ThresholdTable::default) table, see
+/// [`PasswordStrength`](PasswordStrength).
+const DEFAULT_THRESHOLDS: &[(f64, PasswordStrength)] = &[
+    (28.0, PasswordStrength::Weak),
+    (36.0, PasswordStrength::Reasonable),
+    (60.0, PasswordStrength::Strong),
+    (128.0, PasswordStrength::VeryStrong),
+];
+
+/// The strength of a password, classified by its entropy in bits.
+///
+/// The variants are ordered from the weakest to the strongest classification,
+/// so they can be compared and sorted.
+///
+/// | Variant                                   | Entropy in bits   |
+/// |-------------------------------------------|-------------------|
+/// | [`VeryWeak`](PasswordStrength::VeryWeak)     | `bits < 28`       |
+/// | [`Weak`](PasswordStrength::Weak)             | `28 <= bits < 36` |
+/// | [`Reasonable`](PasswordStrength::Reasonable) | `36 <= bits < 60` |
+/// | [`Strong`](PasswordStrength::Strong)         | `60 <= bits < 128`|
+/// | [`VeryStrong`](PasswordStrength::VeryStrong) | `128 <= bits`     |
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PasswordStrength {
+    /// The password has an entropy below 28 bits.
+    VeryWeak,
+    /// The password has an entropy of at least 28 but below 36 bits.
+    Weak,
+    /// The password has an entropy of at least 36 but below 60 bits.
+    Reasonable,
+    /// The password has an entropy of at least 60 but below 128 bits.
+    Strong,
+    /// The password has an entropy of at least 128 bits.
+    VeryStrong,
+}
+
+impl PasswordStrength {
+    /// Classifies the given entropy in bits.
+    ///
+    /// An entropy of `NaN` is classified as [`VeryWeak`](PasswordStrength::VeryWeak).
+    #[must_use]
+    pub fn from_entropy(bits: f64) -> Self {
+        ThresholdTable::default().classify(bits)
+    }
+}
+
+/// The minimum entropy in bits of each [`PasswordStrength`](PasswordStrength),
+/// e.g. to tune the classification to the risk tolerance of an organization.
+///
+/// Each threshold is a pair of the minimum bits and the strength of an entropy
+/// of at least these bits. An entropy below the first threshold is
+/// [`VeryWeak`](PasswordStrength::VeryWeak). The
+/// [`default`](ThresholdTable::default) table uses the thresholds of
+/// [`PasswordStrength`](PasswordStrength).
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::{PasswordInfo, PasswordStrength, ThresholdTable};
+///
+/// let strict = ThresholdTable::new(vec![
+///     (40.0, PasswordStrength::Weak),
+///     (60.0, PasswordStrength::Reasonable),
+///     (80.0, PasswordStrength::Strong),
+///     (128.0, PasswordStrength::VeryStrong),
+/// ])
+/// .expect("thresholds are sorted");
+///
+/// let info = PasswordInfo::for_password("Tr0ub4dor&3");
+/// assert_eq!(PasswordStrength::Strong, info.strength());
+/// assert_eq!(PasswordStrength::Reasonable, info.strength_with(&strict));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThresholdTable {
+    /// The thresholds, sorted by strictly ascending bits.
+    thresholds: Cow<'static, [(f64, PasswordStrength)]>,
+}
+
+impl ThresholdTable {
+    /// Creates a new table of the given thresholds.
+    ///
+    /// ## Errors
+    /// [`PasswordError::UnsortedThresholds`](PasswordError::UnsortedThresholds)
+    /// if the bits are not strictly ascending or any of them is `NaN`.
+    pub fn new(thresholds: Vec<(f64, PasswordStrength)>) -> Result<Self, PasswordError> {
+        let sorted = thresholds.iter().all(|(bits, _)| !bits.is_nan())
+            && thresholds.windows(2).all(|pair| pair[0].0 < pair[1].0);
+        if !sorted {
+            return Err(PasswordError::UnsortedThresholds);
+        }
+
+        Ok(Self {
+            thresholds: Cow::Owned(thresholds),
+        })
+    }
+
+    /// The thresholds, sorted by strictly ascending bits.
+    #[must_use]
+    pub fn thresholds(&self) -> &[(f64, PasswordStrength)] {
+        &self.thresholds
+    }
+
+    /// Classifies the given entropy in bits with the strength of the highest
+    /// threshold it reaches.
+    ///
+    /// An entropy below the first threshold or of `NaN` is classified as
+    /// [`VeryWeak`](PasswordStrength::VeryWeak).
+    #[must_use]
+    pub fn classify(&self, bits: f64) -> PasswordStrength {
+        self.thresholds
+            .iter()
+            .rev()
+            .find(|&&(min_bits, _)| bits >= min_bits)
+            .map_or(PasswordStrength::VeryWeak, |&(_, strength)| strength)
+    }
+}
+
+impl Default for ThresholdTable {
+    fn default() -> Self {
+        Self {
+            thresholds: Cow::Borrowed(DEFAULT_THRESHOLDS),
+        }
+    }
+}
+
+impl fmt::Display for PasswordStrength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::VeryWeak => "VeryWeak",
+            Self::Weak => "Weak",
+            Self::Reasonable => "Reasonable",
+            Self::Strong => "Strong",
+            Self::VeryStrong => "VeryStrong",
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PasswordError, PasswordStrength, ThresholdTable};
+
+    #[test]
+    fn test_display() {
+        assert_eq!("VeryWeak", PasswordStrength::VeryWeak.to_string());
+        assert_eq!("Reasonable", PasswordStrength::Reasonable.to_string());
+        assert_eq!("VeryStrong", PasswordStrength::VeryStrong.to_string());
+    }
+
+    #[test]
+    fn test_from_entropy() {
+        assert_eq!(
+            PasswordStrength::VeryWeak,
+            PasswordStrength::from_entropy(0.0)
+        );
+        assert_eq!(
+            PasswordStrength::VeryWeak,
+            PasswordStrength::from_entropy(27.9)
+        );
+        assert_eq!(PasswordStrength::Weak, PasswordStrength::from_entropy(28.0));
+        assert_eq!(PasswordStrength::Weak, PasswordStrength::from_entropy(35.9));
+        assert_eq!(
+            PasswordStrength::Reasonable,
+            PasswordStrength::from_entropy(36.0)
+        );
+        assert_eq!(
+            PasswordStrength::Reasonable,
+            PasswordStrength::from_entropy(59.9)
+        );
+        assert_eq!(
+            PasswordStrength::Strong,
+            PasswordStrength::from_entropy(60.0)
+        );
+        assert_eq!(
+            PasswordStrength::Strong,
+            PasswordStrength::from_entropy(127.9)
+        );
+        assert_eq!(
+            PasswordStrength::VeryStrong,
+            PasswordStrength::from_entropy(128.0)
+        );
+        assert_eq!(
+            PasswordStrength::VeryWeak,
+            PasswordStrength::from_entropy(f64::NAN)
+        );
+    }
+
+    #[test]
+    fn test_threshold_table() {
+        let table = ThresholdTable::new(vec![
+            (10.0, PasswordStrength::Weak),
+            (20.0, PasswordStrength::VeryStrong),
+        ])
+        .expect("thresholds are sorted");
+        assert_eq!(PasswordStrength::VeryWeak, table.classify(9.9));
+        assert_eq!(PasswordStrength::Weak, table.classify(10.0));
+        assert_eq!(PasswordStrength::VeryStrong, table.classify(20.0));
+        assert_eq!(PasswordStrength::VeryWeak, table.classify(f64::NAN));
+
+        let empty = ThresholdTable::new(Vec::new()).expect("thresholds are sorted");
+        assert_eq!(PasswordStrength::VeryWeak, empty.classify(1000.0));
+
+        for thresholds in [
+            vec![
+                (20.0, PasswordStrength::Weak),
+                (10.0, PasswordStrength::Strong),
+            ],
+            vec![
+                (10.0, PasswordStrength::Weak),
+                (10.0, PasswordStrength::Strong),
+            ],
+            vec![(f64::NAN, PasswordStrength::Weak)],
+        ] {
+            assert_eq!(
+                Some(PasswordError::UnsortedThresholds),
+                ThresholdTable::new(thresholds).err()
+            );
+        }
+
+        assert_eq!(4, ThresholdTable::default().thresholds().len());
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(PasswordStrength::VeryWeak < PasswordStrength::Weak);
+        assert!(PasswordStrength::Weak < PasswordStrength::Reasonable);
+        assert!(PasswordStrength::Reasonable < PasswordStrength::Strong);
+        assert!(PasswordStrength::Strong < PasswordStrength::VeryStrong);
+    }
+}
@@ -0,0 +1,148 @@
+//! The trace of the stripping stages of a password.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// A stage of the stripping of a password, in the order they are applied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TraceStage {
+    /// The password as it was given.
+    Input,
+    /// The control characters were removed.
+    Control,
+    /// A possible palindrome was removed.
+    Palindrome,
+    /// The case of a leading capital was removed.
+    LeadingCapital,
+    /// The case of an alternating case pattern was removed.
+    CasePattern,
+    /// The common sequences were removed.
+    Sequences,
+    /// The dictionary words were removed.
+    Dictionary,
+    /// The keyboard walks were removed.
+    KeyboardWalks,
+    /// The dates were reduced.
+    Dates,
+    /// The repeating characters and patterns were removed.
+    Repeats,
+}
+
+/// The password after each stripping stage, see
+/// [`PasswordInfoBuilder::trace`](crate::PasswordInfoBuilder::trace).
+///
+/// Only the stages that are enabled by the builder are recorded. The last
+/// recorded stage is the stripped password the entropy is calculated of.
+///
+/// **The trace retains the plaintext password and each intermediate stage of
+/// it** for as long as the [`PasswordInfo`](crate::PasswordInfo) lives. If the
+/// feature `zeroize` is activated, the stages are overwritten with zeros when
+/// the info is dropped, but every copy handed out before, like a cloned info
+/// or a string taken from [`stages`](AnalysisTrace::stages), is not. The
+/// [`Debug`](fmt::Debug) output only contains the stages and their lengths.
+///
+/// ## Example
+/// ```rust
+/// use pw_entropy::{PasswordInfoBuilder, TraceStage};
+///
+/// let info = PasswordInfoBuilder::new().trace(true).for_password("password7777");
+/// let trace = info.trace().expect("trace is enabled");
+/// assert_eq!(Some("password7777"), trace.after(TraceStage::Input));
+/// assert_eq!(Some("7777"), trace.after(TraceStage::Sequences));
+/// assert_eq!("7", trace.output());
+/// ```
+#[derive(Clone, Default)]
+pub struct AnalysisTrace {
+    /// The recorded stages with the password after each of them.
+    stages: Vec<(TraceStage, String)>,
+}
+
+impl AnalysisTrace {
+    /// The recorded stages in the order they were applied, each with the
+    /// password after the stage.
+    #[must_use]
+    pub fn stages(&self) -> &[(TraceStage, String)] {
+        &self.stages
+    }
+
+    /// The password after the given stage, or `None` if the stage was not
+    /// enabled.
+    #[must_use]
+    pub fn after(&self, stage: TraceStage) -> Option<&str> {
+        self.stages
+            .iter()
+            .find(|(recorded, _)| *recorded == stage)
+            .map(|(_, password)| password.as_str())
+    }
+
+    /// The password after the last stage.
+    #[must_use]
+    pub fn output(&self) -> &str {
+        self.stages
+            .last()
+            .map_or("", |(_, password)| password.as_str())
+    }
+}
+
+/// Records the password after the given stage, if the trace is enabled.
+pub fn record(trace: &mut Option<AnalysisTrace>, stage: TraceStage, password: &[char]) {
+    if let Some(trace) = trace {
+        trace.stages.push((stage, password.iter().collect()));
+    }
+}
+
+impl fmt::Debug for AnalysisTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                self.stages
+                    .iter()
+                    .map(|(stage, password)| (stage, password.chars().count())),
+            )
+            .finish()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for AnalysisTrace {
+    fn zeroize(&mut self) {
+        for (_, password) in &mut self.stages {
+            password.zeroize();
+        }
+        self.stages.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PasswordInfo, PasswordInfoBuilder, TraceStage};
+
+    #[test]
+    fn test_trace() {
+        assert!(PasswordInfo::for_password("abcabc").trace().is_none());
+
+        let info = PasswordInfoBuilder::new()
+            .trace(true)
+            .for_password("Xyz1\u{7}1zyX");
+        let trace = info.trace().expect("trace is enabled");
+        let stages: Vec<TraceStage> = trace.stages().iter().map(|(stage, _)| *stage).collect();
+        assert_eq!(
+            vec![
+                TraceStage::Input,
+                TraceStage::Control,
+                TraceStage::Palindrome,
+                TraceStage::Sequences,
+                TraceStage::Repeats,
+            ],
+            stages
+        );
+        assert_eq!(Some("Xyz1\u{7}1zyX"), trace.after(TraceStage::Input));
+        assert_eq!(Some("Xyz11zyX"), trace.after(TraceStage::Control));
+        assert_eq!(Some("Xyz1"), trace.after(TraceStage::Palindrome));
+        assert_eq!(None, trace.after(TraceStage::Dates));
+        assert_eq!(info.length(), trace.output().chars().count());
+    }
+}
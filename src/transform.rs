@@ -0,0 +1,99 @@
+//! The transformations that remove the predictable parts of a password, to
+//! build custom preprocessing pipelines.
+//!
+//! These are the same transformations [`PasswordInfo::for_password`](crate::PasswordInfo::for_password)
+//! applies. All of them mutate the given characters in place and only ever
+//! remove characters.
+//!
+//! ## Example
+//! ```rust
+//! use pw_entropy::transform;
+//!
+//! let mut password: Vec<char> = "xqwertyzzzyx".chars().collect();
+//! assert!(!transform::remove_palindrome(&mut password));
+//! assert_eq!(1, transform::remove_common_sequences(&mut password, &["qwerty"]));
+//! assert!(transform::remove_repeating_characters(&mut password));
+//! assert_eq!(vec!['x', 'z', 'y', 'x'], password);
+//! ```
+
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::sequence::SequenceSet;
+
+/// The built-in list of common sequences that
+/// [`PasswordInfo::for_password`](crate::PasswordInfo::for_password) removes.
+///
+/// The list is parsed from the bundled file `data/common.txt` and, with the
+/// feature `large-wordlist`, `data/common-large.txt`. With the feature `std`
+/// it is only parsed once and borrowed, without it is parsed on each call.
+#[must_use]
+pub fn common_sequences() -> Cow<'static, [&'static str]> {
+    crate::common_sequences()
+}
+
+/// Removes the second half of the password in place, if the password is a
+/// palindrome ignoring the case. For an odd length the middle character is
+/// kept, so `abba` becomes `ab` and `abcba` becomes `abc`.
+///
+/// Returns true, if the palindrome was removed.
+pub fn remove_palindrome(password: &mut Vec<char>) -> bool {
+    let length = password.len();
+    crate::remove_palindrome(password, None, 0);
+    password.len() != length
+}
+
+/// Removes all occurrences of the given sequences in place, forwards and
+/// reversed.
+///
+/// The removal is repeated until nothing is removed anymore, so `qwe1234rty`
+/// with the sequences `1234` and `qwerty` becomes empty. Empty sequences are
+/// ignored.
+///
+/// Returns the amount of removed occurrences.
+pub fn remove_common_sequences(password: &mut Vec<char>, sequences: &[&str]) -> usize {
+    crate::remove_common_sequences(password, None, &SequenceSet::new(sequences), false).len()
+}
+
+/// Removes repeating characters in place, so `aaabbc` becomes `abc`.
+///
+/// Returns true, if any character was removed.
+pub fn remove_repeating_characters(password: &mut Vec<char>) -> bool {
+    let length = password.len();
+    crate::remove_repeating_characters(password, None, false, 1);
+    password.len() != length
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{transform, PasswordInfo};
+
+    #[test]
+    fn test_transform() {
+        let mut password: Vec<char> = "abcba".chars().collect();
+        assert!(transform::remove_palindrome(&mut password));
+        assert_eq!(vec!['a', 'b', 'c'], password);
+        assert!(!transform::remove_palindrome(&mut password));
+
+        let mut password: Vec<char> = "qwe1234rty!".chars().collect();
+        assert_eq!(
+            2,
+            transform::remove_common_sequences(&mut password, &["1234", "qwerty", ""])
+        );
+        assert_eq!(vec!['!'], password);
+
+        let mut password: Vec<char> = "aaabbc".chars().collect();
+        assert!(transform::remove_repeating_characters(&mut password));
+        assert_eq!(vec!['a', 'b', 'c'], password);
+        assert!(!transform::remove_repeating_characters(&mut password));
+    }
+
+    #[test]
+    fn test_common_sequences() {
+        let mut password: Vec<char> = "xqwertyz".chars().collect();
+        let _ = transform::remove_common_sequences(&mut password, &transform::common_sequences());
+        assert_eq!(
+            PasswordInfo::for_password("xqwertyz").length(),
+            password.len()
+        );
+    }
+}
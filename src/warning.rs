@@ -0,0 +1,105 @@
+//! The machine-readable warnings of a password.
+
+use alloc::string::String;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A machine-readable weakness of a password, see
+/// [`PasswordInfo::warnings`](crate::PasswordInfo::warnings).
+///
+/// Unlike the English [`suggestions`](crate::PasswordInfo::suggestions), each
+/// warning can be mapped to a localized text.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Warning {
+    /// The stripped length is below the
+    /// [`RECOMMENDED_LENGTH`](crate::RECOMMENDED_LENGTH) or the password is
+    /// [`too short`](crate::PasswordInfo::is_too_short).
+    TooShort,
+    /// The password is
+    /// [`suspiciously long`](crate::PasswordInfo::is_suspiciously_long).
+    SuspiciouslyLong,
+    /// The password does not contain a lowercase letter.
+    MissingLowercase,
+    /// The password does not contain an uppercase letter.
+    MissingUppercase,
+    /// The password does not contain a digit.
+    MissingDigit,
+    /// The password does not contain a replace character.
+    MissingReplace,
+    /// The password does not contain a separator character.
+    MissingSeparator,
+    /// The password does not contain another special character.
+    MissingOtherSpecial,
+    /// The base is below the
+    /// [`minimum meaningful base`](crate::PasswordInfo::min_meaningful_base).
+    TinyAlphabet,
+    /// The password contains the given common sequence. The sequence is owned,
+    /// because it may be a custom sequence of a
+    /// [`PasswordInfoBuilder`](crate::PasswordInfoBuilder).
+    ContainsCommonSequence(String),
+    /// The password is a palindrome.
+    IsPalindrome,
+    /// The password contains repeating characters or patterns.
+    RepeatedCharacters,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{PasswordInfo, PasswordInfoBuilder, Warning};
+
+    #[test]
+    fn test_warnings() {
+        let warnings = PasswordInfo::for_password("xqwertyz").warnings();
+        assert_eq!(
+            vec![
+                Warning::TooShort,
+                Warning::MissingUppercase,
+                Warning::MissingDigit,
+                Warning::MissingReplace,
+                Warning::MissingSeparator,
+                Warning::MissingOtherSpecial,
+                Warning::ContainsCommonSequence("qwerty".into()),
+            ],
+            warnings
+        );
+
+        let password = "Tr0ub4dor&3 is_ok#long";
+        let palindrome: String = password.chars().chain(password.chars().rev()).collect();
+        let warnings = PasswordInfo::for_password(&palindrome).warnings();
+        assert_eq!(vec![Warning::IsPalindrome], warnings);
+
+        assert!(PasswordInfo::for_password(password).warnings().is_empty());
+
+        let warnings = PasswordInfo::for_password("Tr0ub4dor&3 is_ok#looong").warnings();
+        assert_eq!(vec![Warning::RepeatedCharacters], warnings);
+
+        let warnings = PasswordInfoBuilder::new()
+            .min_meaningful_base(100)
+            .for_password(password)
+            .warnings();
+        assert_eq!(vec![Warning::TinyAlphabet], warnings);
+    }
+
+    #[test]
+    fn test_warnings_match_suggestions() {
+        for password in &["", "letmein", "Tr0ub4dor&3", "correct horse battery staple"] {
+            let info = PasswordInfo::for_password(password);
+            let missing = info
+                .warnings()
+                .into_iter()
+                .filter(|warning| {
+                    !matches!(
+                        warning,
+                        Warning::ContainsCommonSequence(_)
+                            | Warning::IsPalindrome
+                            | Warning::RepeatedCharacters
+                            | Warning::TinyAlphabet
+                    )
+                })
+                .count();
+            assert_eq!(info.suggestions().len(), missing);
+        }
+    }
+}
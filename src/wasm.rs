@@ -0,0 +1,169 @@
+//! A [`wasm-bindgen`](wasm_bindgen) wrapper of the [`PasswordInfo`](PasswordInfo)
+//! for the use in JavaScript.
+//!
+//! ## Example
+//! ```js
+//! import { PasswordInfo } from "pw_entropy";
+//!
+//! const info = PasswordInfo.for_password("ThisIsASecret");
+//! console.log(info.entropy, info.strength, info.has_upper);
+//! ```
+// `wasm-bindgen` does not support `const` functions.
+#![allow(clippy::missing_const_for_fn)]
+
+use alloc::string::{String, ToString};
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{PasswordInfo, PasswordStrength};
+
+/// The information about a password, calculated by
+/// [`PasswordInfo::for_password`](PasswordInfo::for_password). It is named
+/// `PasswordInfo` in JavaScript and all values are exposed as read-only
+/// properties.
+#[wasm_bindgen(js_name = PasswordInfo)]
+#[derive(Clone, Copy, Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct WasmPasswordInfo {
+    /// The entropy of the password in bits.
+    entropy: f64,
+    /// The stripped length of the password.
+    length: usize,
+    /// The calculated base of the password.
+    base: u16,
+    /// The classification of the entropy.
+    strength: PasswordStrength,
+    /// The password contains at least one replace character.
+    has_replace: bool,
+    /// The password contains at least one separator character.
+    has_separator: bool,
+    /// The password contains at least one other special character.
+    has_other_special: bool,
+    /// The password contains at least one lower character.
+    has_lower: bool,
+    /// The password contains at least one upper character.
+    has_upper: bool,
+    /// The password contains at least one digit.
+    has_digit: bool,
+}
+
+#[wasm_bindgen(js_class = PasswordInfo)]
+impl WasmPasswordInfo {
+    /// Calculates the information about the given password like
+    /// [`PasswordInfo::for_password`](PasswordInfo::for_password).
+    #[must_use]
+    pub fn for_password(password: &str) -> Self {
+        Self::from(&PasswordInfo::for_password(password))
+    }
+
+    /// The entropy of the password in bits.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn entropy(&self) -> f64 {
+        self.entropy
+    }
+
+    /// The stripped length of the password.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// The calculated base of the password.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn base(&self) -> u16 {
+        self.base
+    }
+
+    /// The classification of the entropy, which is one of `VeryWeak`,
+    /// `Weak`, `Reasonable`, `Strong` or `VeryStrong`.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn strength(&self) -> String {
+        self.strength.to_string()
+    }
+
+    /// True, if the password contains replace characters.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn has_replace(&self) -> bool {
+        self.has_replace
+    }
+
+    /// True, if the password contains separator characters.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn has_separator(&self) -> bool {
+        self.has_separator
+    }
+
+    /// True, if the password contains other special characters.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn has_other_special(&self) -> bool {
+        self.has_other_special
+    }
+
+    /// True, if the password contains at least one lower character.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn has_lower(&self) -> bool {
+        self.has_lower
+    }
+
+    /// True, if the password contains at least one upper character.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn has_upper(&self) -> bool {
+        self.has_upper
+    }
+
+    /// True, if the password contains at least one digit.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn has_digit(&self) -> bool {
+        self.has_digit
+    }
+}
+
+impl From<&PasswordInfo> for WasmPasswordInfo {
+    fn from(info: &PasswordInfo) -> Self {
+        Self {
+            entropy: info.get_entropy(),
+            length: info.length(),
+            base: info.base(),
+            strength: info.strength(),
+            has_replace: info.has_replace_character(),
+            has_separator: info.has_separator_character(),
+            has_other_special: info.has_other_special_character(),
+            has_lower: info.has_lower_character(),
+            has_upper: info.has_upper_character(),
+            has_digit: info.has_digit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{wasm::WasmPasswordInfo, PasswordInfo};
+
+    #[test]
+    fn test_for_password() {
+        let password = "Tr0ub4dor&3";
+        let info = PasswordInfo::for_password(password);
+        let wasm = WasmPasswordInfo::for_password(password);
+
+        assert!((info.get_entropy() - wasm.entropy()).abs() < f64::EPSILON);
+        assert_eq!(info.length(), wasm.length());
+        assert_eq!(info.base(), wasm.base());
+        assert_eq!("Strong", wasm.strength());
+        assert!(wasm.has_replace());
+        assert!(!wasm.has_separator());
+        assert!(!wasm.has_other_special());
+        assert!(wasm.has_lower());
+        assert!(wasm.has_upper());
+        assert!(wasm.has_digit());
+    }
+}